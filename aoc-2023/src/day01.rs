@@ -0,0 +1,137 @@
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
+
+pub struct Day01;
+register!(2023, 1, Day01);
+
+fn parse_number(s: &str) -> Option<char> {
+    let max_idx = s.len();
+
+    match &s[..3.min(max_idx)] {
+        "one" => Some('1'),
+        "two" => Some('2'),
+        "six" => Some('6'),
+        _ => match &s[..4.min(max_idx)] {
+            "four" => Some('4'),
+            "five" => Some('5'),
+            "nine" => Some('9'),
+            _ => match &s[..5.min(max_idx)] {
+                "three" => Some('3'),
+                "seven" => Some('7'),
+                "eight" => Some('8'),
+                _ => None,
+            },
+        },
+    }
+}
+
+fn process_line_p2(line: &str) -> Result<i32> {
+    let mut n1 = None;
+    let mut n2 = None;
+    for (i, c) in line.char_indices() {
+        if c.is_ascii_digit() {
+            if n1.is_none() {
+                n1 = Some(c);
+            }
+            n2 = Some(c);
+        } else if let Some(d) = parse_number(&line[i..]) {
+            if n1.is_none() {
+                n1 = Some(d);
+            }
+            n2 = Some(d);
+        } else {
+            continue;
+        }
+    }
+    calibration_value(line, n1, n2)
+}
+
+fn process_line(line: &str) -> Result<i32> {
+    let n1 = line.chars().find(char::is_ascii_digit);
+    let n2 = line.chars().rev().find(char::is_ascii_digit);
+    calibration_value(line, n1, n2)
+}
+
+/// The two-digit number of the first and last digit of `line`.
+fn calibration_value(line: &str, first: Option<char>, last: Option<char>) -> Result<i32> {
+    first
+        .zip(last)
+        .and_then(|(c1, c2)| Some(c1.to_digit(10)? * 10 + c2.to_digit(10)?))
+        .map(|value| value as i32)
+        .ok_or_else(|| Error::parse("a line with a digit", line))
+}
+
+impl Solution for Day01 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let values = utils::parse_lines(input, process_line)?;
+        Ok(values.iter().sum::<i32>().into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let values = utils::parse_lines(input, process_line_p2)?;
+        Ok(values.iter().sum::<i32>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        let result = Day01.part1(input);
+        assert_eq!(result, Ok(142.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        let result = Day01.part2(input);
+        assert_eq!(result, Ok(281.into()));
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("one44444444"), Some('1'));
+        assert_eq!(parse_number("nine"), Some('9'));
+    }
+    #[test]
+    fn test_process_line() {
+        assert_eq!(process_line("1abc2"), Ok(12));
+        assert_eq!(process_line("pqr3stu8vwx"), Ok(38));
+        assert_eq!(process_line("a1b2c3d4e5f"), Ok(15));
+        assert_eq!(process_line("treb7uchet"), Ok(77));
+    }
+    #[test]
+    fn test_process_line_p2() {
+        assert_eq!(process_line_p2("two1one1"), Ok(21));
+        assert_eq!(process_line_p2("two1one"), Ok(21));
+        assert_eq!(process_line_p2("nine"), Ok(99));
+        assert_eq!(process_line_p2("two1nine"), Ok(29));
+        assert_eq!(process_line_p2("eightwothree"), Ok(83));
+        assert_eq!(process_line_p2("abcone2threexyz"), Ok(13));
+        assert_eq!(process_line_p2("xtwone3four"), Ok(24));
+        assert_eq!(process_line_p2("4nineeightseven2"), Ok(42));
+        assert_eq!(process_line_p2("zoneight234"), Ok(14));
+        assert_eq!(process_line_p2("7pqrstsixteen"), Ok(76));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day01.part1("1abc2\nabc").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a line with a digit, found \"abc\""
+        );
+        assert!(Day01.part2("two1\nxyz").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
+use regex::Regex;
+
+pub struct Day02;
+register!(2023, 2, Day02);
+
+/// Returns the game ID, whether the game is possible and its minimal set of cubes.
+fn solve_game(game_line: &str) -> Result<(i32, bool, CubeSet)> {
+    let (game, withdrawals) = game_line
+        .split_once(':')
+        .ok_or_else(|| Error::parse("`Game N: cubes; ...`", game_line))?;
+    let game_id = parse_game_id(game).map_err(|err| err.within(game_line, game))?;
+    let mut game_possible = true;
+    let mut minimal = CubeSet::new(0, 0, 0);
+    for s_withdrawal in withdrawals.split(';') {
+        let one_withdrawal = parse_one_withdrawal(s_withdrawal);
+        minimal.update_minimal(&one_withdrawal);
+        game_possible = game_possible && one_withdrawal.is_possible();
+    }
+    Ok((game_id, game_possible, minimal))
+}
+
+fn parse_game_id(s: &str) -> Result<i32> {
+    let id = s
+        .strip_prefix("Game ")
+        .ok_or_else(|| Error::parse("`Game N`", s))?;
+    utils::parse_token(s, id, "game ID")
+}
+
+fn parse_one_withdrawal(s: &str) -> CubeSet {
+    fn parse_color(regex: &Regex, s: &str) -> i32 {
+        regex
+            .captures(s)
+            .and_then(|cap| cap[1].parse::<i32>().ok())
+            .unwrap_or(0)
+    }
+    let re_r = Regex::new(r"(?<n_red>\d+) red").unwrap();
+    let re_g = Regex::new(r"(?<n_green>\d+) green").unwrap();
+    let re_b = Regex::new(r"(?<n_blue>\d+) blue").unwrap();
+
+    let r = parse_color(&re_r, s);
+    let g = parse_color(&re_g, s);
+    let b = parse_color(&re_b, s);
+
+    CubeSet::new(r, g, b)
+}
+
+#[derive(Debug, PartialEq)]
+struct CubeSet {
+    r: i32,
+    g: i32,
+    b: i32,
+}
+
+impl CubeSet {
+    const MAX_R: i32 = 12;
+    const MAX_G: i32 = 13;
+    const MAX_B: i32 = 14;
+
+    fn new(r: i32, g: i32, b: i32) -> Self {
+        Self { r, g, b }
+    }
+
+    fn is_possible(&self) -> bool {
+        self.r <= Self::MAX_R && self.g <= Self::MAX_G && self.b <= Self::MAX_B
+    }
+
+    fn update_minimal(&mut self, other: &CubeSet) {
+        self.r = self.r.max(other.r);
+        self.g = self.g.max(other.g);
+        self.b = self.b.max(other.b);
+    }
+
+    fn get_power(&self) -> i32 {
+        self.r * self.g * self.b
+    }
+}
+
+impl Solution for Day02 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let games = utils::parse_lines(input, solve_game)?;
+        Ok(games
+            .iter()
+            .filter(|(_, possible, _)| *possible)
+            .map(|(id, _, _)| *id)
+            .sum::<i32>()
+            .into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let games = utils::parse_lines(input, solve_game)?;
+        Ok(games
+            .iter()
+            .map(|(_, _, minimal)| minimal.get_power())
+            .sum::<i32>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let result = Day02.part1(input);
+        assert_eq!(result, Ok(8.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let result = Day02.part2(input);
+        assert_eq!(result, Ok(2286.into()));
+    }
+
+    #[test]
+    fn parge_game_id_test() {
+        assert_eq!(parse_game_id("Game 2"), Ok(2));
+        assert_eq!(parse_game_id("Game 21"), Ok(21));
+        assert_eq!(parse_game_id("Game 999"), Ok(999));
+    }
+
+    #[test]
+    fn parse_one_withdrawal_test() {
+        assert_eq!(parse_one_withdrawal("3 blue, 4 red"), CubeSet::new(4, 0, 3));
+        assert_eq!(
+            parse_one_withdrawal("4 red, 1 green, 5 blue"),
+            CubeSet::new(4, 1, 5)
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day02.part1("Game 1: 3 blue\nGame x: 4 red").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected game ID, found \"x\""
+        );
+        assert!(Day02.part2("Game 1 3 blue").is_err());
+    }
+}
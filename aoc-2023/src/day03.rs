@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use aoc_core::utils::{Coord, Grid};
+use aoc_core::{register, Answer, Result, Solution};
+use regex::Regex;
+
+pub struct Day03;
+register!(2023, 3, Day03);
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Clone)]
+struct Point {
+    x: usize, // horizontal ->
+    y: usize, // vertical   ↓
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+struct Gear {
+    ratio: i32,
+    n_numbers: i32,
+}
+
+/// Points around the number spanning `left..=right`, clipped to `0..=max`.
+fn get_envelope(left: &Point, right: &Point, max: &Point) -> Vec<Point> {
+    assert!(left.y == right.y);
+    let top_left = Point {
+        x: left.x.saturating_sub(1),
+        y: left.y.saturating_sub(1),
+    };
+    let bot_right = Point {
+        x: right.x.saturating_add(1).min(max.x),
+        y: right.y.saturating_add(1).min(max.y),
+    };
+
+    (top_left.x..=bot_right.x)
+        .flat_map(|x| (top_left.y..=bot_right.y).map(move |y| (x, y)))
+        .filter(|(x, y)| !(y == &left.y && &left.x <= x && x <= &right.x))
+        .map(|(x, y)| Point { x, y })
+        .collect::<Vec<Point>>()
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_numeric()
+}
+fn is_gear(c: char) -> bool {
+    c == '*'
+}
+
+/// Returns the sum of the part numbers and the sum of the gear ratios.
+fn solve(input: &str) -> Result<(i32, i32)> {
+    let mut res = 0;
+    let mut gears_seen = HashMap::<Point, Gear>::new();
+    let schematic = Grid::parse(input, Ok)?;
+    let max = Point {
+        x: schematic.width().saturating_sub(1),
+        y: schematic.height().saturating_sub(1),
+    };
+    let re_num = Regex::new(r"(\d+)").unwrap();
+    for (y, line) in input.lines().enumerate() {
+        for number_match in re_num.find_iter(line) {
+            let number = &line[number_match.start()..number_match.end()];
+            let location = (number_match.start(), number_match.end() - 1);
+            let envelope = get_envelope(
+                &Point { x: location.0, y },
+                &Point { x: location.1, y },
+                &max,
+            );
+            let mut summed = false;
+            for point in envelope {
+                let c = schematic[Coord {
+                    x: point.x as i32,
+                    y: point.y as i32,
+                }];
+                let n = number.parse::<i32>().ok().unwrap_or(0);
+                if !summed && is_symbol(c) {
+                    res += n;
+                    summed = true;
+                }
+                if is_gear(c) {
+                    gears_seen
+                        .entry(point)
+                        .and_modify(|g| {
+                            g.ratio *= n;
+                            g.n_numbers += 1;
+                        })
+                        .or_insert_with(|| Gear {
+                            ratio: n,
+                            n_numbers: 1,
+                        });
+                }
+            }
+        }
+    }
+    let res2 = gears_seen
+        .iter()
+        .filter(|(_k, v)| v.n_numbers == 2)
+        .fold(0, |acc, (_k, v)| acc + v.ratio);
+    Ok((res, res2))
+}
+
+impl Solution for Day03 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(solve(input)?.0.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(solve(input)?.1.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let result = Day03.part1(input);
+        assert_eq!(result, Ok(4361.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let result = Day03.part2(input);
+        assert_eq!(result, Ok(467835.into()));
+    }
+
+    fn sorted(mut points: Vec<Point>) -> Vec<Point> {
+        points.sort();
+        points
+    }
+
+    #[test]
+    fn get_envelope_test() {
+        let max = Point { x: 139, y: 139 };
+        assert_eq!(
+            sorted(get_envelope(
+                &Point { x: 0, y: 0 },
+                &Point { x: 2, y: 0 },
+                &max
+            )),
+            sorted(vec![
+                Point { x: 0, y: 1 },
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 3, y: 1 },
+                Point { x: 3, y: 0 }
+            ])
+        );
+        assert_eq!(
+            sorted(get_envelope(
+                &Point { x: 1, y: 1 },
+                &Point { x: 4, y: 1 },
+                &max
+            )),
+            sorted(vec![
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 0 },
+                Point { x: 2, y: 0 },
+                Point { x: 3, y: 0 },
+                Point { x: 4, y: 0 },
+                Point { x: 5, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: 5, y: 1 },
+                Point { x: 0, y: 2 },
+                Point { x: 1, y: 2 },
+                Point { x: 2, y: 2 },
+                Point { x: 3, y: 2 },
+                Point { x: 4, y: 2 },
+                Point { x: 5, y: 2 }
+            ])
+        );
+    }
+
+    #[test]
+    fn is_symbol_test() {
+        assert!(is_symbol('@'));
+        assert!(is_symbol('%'));
+        assert!(is_symbol('!'));
+        assert!(is_symbol('?'));
+
+        assert!(!is_symbol('.'));
+        assert!(!is_symbol('4'));
+        assert!(!is_symbol('9'));
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
+
+pub struct Day04;
+register!(2023, 4, Day04);
+
+fn solve_card(winning: &[i32], mine: &[i32]) -> (i32, i32) {
+    let mut points = 0;
+    let mut matches = 0;
+    for n in winning {
+        if mine.contains(n) {
+            if matches == 0 {
+                points = 1;
+            } else {
+                points *= 2;
+            }
+            matches += 1;
+        }
+    }
+    (points, matches)
+}
+/// The winning numbers and the numbers you have of a card.
+fn parse_card(line: &str) -> Result<(Vec<i32>, Vec<i32>)> {
+    let (_, numbers) = utils::key_value(line)?;
+    let (winning, mine) = numbers
+        .split_once('|')
+        .ok_or_else(|| Error::parse_at(line, numbers, "`WINNING | MINE`"))?;
+    Ok((
+        utils::integers(winning).map_err(|err| err.within(line, winning))?,
+        utils::integers(mine).map_err(|err| err.within(line, mine))?,
+    ))
+}
+
+/// Returns the total points and the total number of scratchcards.
+fn solve(input: &str) -> Result<(i32, i32)> {
+    let cards = utils::parse_lines(input, parse_card)?;
+    let mut res = 0;
+    let mut multipliers = vec![1; cards.len()];
+    for (idx, (winning, mine)) in cards.iter().enumerate() {
+        let (points, matches) = solve_card(winning, mine);
+        res += points;
+        let base_mult = multipliers[idx];
+        for multiplier in &mut multipliers[idx + 1..idx + 1 + matches as usize] {
+            *multiplier += base_mult;
+        }
+    }
+
+    let res2 = multipliers.iter().sum();
+    Ok((res, res2))
+}
+
+impl Solution for Day04 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(solve(input)?.0.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(solve(input)?.1.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let result = Day04.part1(input);
+        assert_eq!(result, Ok(13.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let result = Day04.part2(input);
+        assert_eq!(result, Ok(30.into()));
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_core::utils::interval::{Interval, RangeMap, RangeSet};
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
+
+pub struct Day05;
+register!(2023, 5, Day05);
+
+/// Parses a map, a line naming it followed by a line per range.
+fn parse_map(block: &str) -> Result<RangeMap> {
+    let mut map = RangeMap::new();
+    for line in block.lines().skip(1) {
+        // the destination range start, the source range start, and the range length.
+        let (destination_start, source_start, range_len): (i64, i64, i64) =
+            utils::integer_tuple(line).map_err(|err| err.within(block, line))?;
+        map.insert(
+            Interval::with_len(source_start, range_len),
+            destination_start - source_start,
+        )
+        .map_err(|err| err.within(block, line))?;
+    }
+    Ok(map)
+}
+
+/// The seeds and the map from seeds to locations, which is all maps of the
+/// almanac one after another.
+fn parse(input: &str) -> Result<(Vec<i64>, RangeMap)> {
+    let mut blocks = utils::blocks(input);
+    let seeds_line = blocks.next().unwrap_or_default();
+    let seeds = utils::header(seeds_line, "seeds").map_err(|err| err.within(input, seeds_line))?;
+    let seeds = utils::integers(seeds).map_err(|err| err.within(input, seeds))?;
+
+    let mut seed_to_location = RangeMap::new();
+    for block in blocks {
+        let map = parse_map(block).map_err(|err| err.within(input, block))?;
+        seed_to_location = seed_to_location.then(&map);
+    }
+    Ok((seeds, seed_to_location))
+}
+
+impl Solution for Day05 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let (seeds, seed_to_location) = parse(input)?;
+        let res1 = seeds
+            .iter()
+            .map(|&seed| seed_to_location.get(seed))
+            .min()
+            .ok_or_else(|| Error::solve("no seeds to plant"))?;
+        Ok(res1.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let (seeds, seed_to_location) = parse(input)?;
+        if seeds.len() % 2 != 0 {
+            return Err(Error::input(
+                "the seeds don't come in pairs of start and length",
+            ));
+        }
+        // The seeds are pairs of the first seed and how many follow it.
+        let seeds: RangeSet = seeds
+            .chunks(2)
+            .map(|pair| Interval::with_len(pair[0], pair[1]))
+            .collect();
+        let res2 = seed_to_location
+            .map_set(&seeds)
+            .min()
+            .ok_or_else(|| Error::solve("no seeds to plant"))?;
+        Ok(res2.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let result = Day05.part1(input);
+        assert_eq!(result, Ok(35.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let result = Day05.part2(input);
+        assert_eq!(result, Ok(46.into()));
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
+
+pub struct Day06;
+register!(2023, 6, Day06);
+
+fn calc_times_for_d(d: u128, total_time: u128) -> (f64, f64) {
+    let discriminant = total_time.pow(2) - 4 * d;
+    let t1 = 0.5 * (total_time as f64 - (discriminant as f64).sqrt());
+    let t2 = 0.5 * (total_time as f64 + (discriminant as f64).sqrt());
+    (t1, t2)
+}
+
+/// Number of whole charging times that travel strictly further than `d`.
+fn ways_to_win(d: u128, total_time: u128) -> i64 {
+    let (t1, t2) = calc_times_for_d(d, total_time);
+    (t2.ceil() - 1.0) as i64 - (t1.floor() + 1.0) as i64 + 1
+}
+
+fn split_lines(input: &str) -> Result<(&str, &str)> {
+    let mut lines = input.lines();
+    let mut values = |key| {
+        let line = lines.next().unwrap_or_default();
+        utils::header(line, key).map_err(|err| err.within(input, line))
+    };
+    Ok((values("Time")?, values("Distance")?))
+}
+
+/// The number the values make when the spaces between them are dropped.
+fn joined(input: &str, values: &str) -> Result<u128> {
+    let digits: String = values.split_whitespace().collect();
+    digits
+        .parse()
+        .map_err(|_| Error::parse_at(input, values, "integers"))
+}
+
+impl Solution for Day06 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let (times_str, dists_str) = split_lines(input)?;
+        let times: Vec<u128> =
+            utils::integers(times_str).map_err(|err| err.within(input, times_str))?;
+        let dists: Vec<u128> =
+            utils::integers(dists_str).map_err(|err| err.within(input, dists_str))?;
+
+        let res1 = times
+            .iter()
+            .zip(dists.iter())
+            .fold(1, |acc, (&t, &d)| acc * ways_to_win(d, t));
+        Ok(res1.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let (times_str, dists_str) = split_lines(input)?;
+        let time_p2 = joined(input, times_str)?;
+        let dist_p2 = joined(input, dists_str)?;
+
+        Ok(ways_to_win(dist_p2, time_p2).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "Time:      7  15   30
+Distance:  9  40  200";
+        let result = Day06.part1(input);
+        assert_eq!(result, Ok(288.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "Time:      7  15   30
+Distance:  9  40  200";
+        let result = Day06.part2(input);
+        assert_eq!(result, Ok(71503.into()));
+    }
+}
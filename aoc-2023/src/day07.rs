@@ -1,6 +1,11 @@
-use crate::utils::*;
 use std::collections::HashMap;
 
+use aoc_core::utils::*;
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day07;
+register!(2023, 7, Day07);
+
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
 enum HandType {
     FiveOfKind = 7,
@@ -33,15 +38,15 @@ struct Hand {
 }
 
 impl Hand {
-    fn from_inputs(cards: &str, bid: u32, with_joker: bool) -> Self {
-        let cards_array = Self::get_cards_array(cards, with_joker);
+    fn from_inputs(cards: &str, bid: u32, with_joker: bool) -> Result<Self> {
+        let cards_array = Self::get_cards_array(cards, with_joker)?;
         let calculated_hand_type = Self::calc_hand_type(cards_array);
 
-        Self {
+        Ok(Self {
             cards: cards_array,
             hand_type: calculated_hand_type,
             bid,
-        }
+        })
     }
 
     fn calc_hand_type(cards: [u8; 5]) -> HandType {
@@ -83,53 +88,85 @@ impl Hand {
         }
     }
 
-    fn get_cards_array(cards: &str, with_joker: bool) -> [u8; 5] {
-        assert!(cards.len() == 5);
+    fn get_cards_array(cards: &str, with_joker: bool) -> Result<[u8; 5]> {
+        if cards.chars().count() != 5 {
+            return Err(Error::parse("five cards", cards));
+        }
         let card_repr = if with_joker {
             "J23456789TQKA"
         } else {
             "23456789TJQKA"
         };
         let mut res = [0; 5];
-        for (i, c) in cards.chars().enumerate() {
-            res[i] = card_repr.find(c).unwrap() as u8;
+        for (i, (idx, c)) in cards.char_indices().enumerate() {
+            let token = &cards[idx..idx + c.len_utf8()];
+            res[i] = card_repr
+                .find(c)
+                .ok_or_else(|| Error::parse_at(cards, token, "a card"))? as u8;
             if with_joker {
                 res[i] += 1u8
             } else {
                 res[i] += 2u8
             }
         }
-        res
+        Ok(res)
     }
 }
 
-pub fn solve_d7() -> (u32, u32) {
-    let lines = read_lines("data/d07.txt");
-    let mut hands_p1 = Vec::with_capacity(lines.len());
-    let mut hands_p2 = Vec::with_capacity(lines.len());
-    for line in lines {
-        let mut sp = line.split(' ');
-        let sp1 = sp.next().unwrap();
-        let sp2 = sp.next().unwrap();
-        hands_p1.push(Hand::from_inputs(sp1, str_to::<u32>(sp2), false));
-        hands_p2.push(Hand::from_inputs(sp1, str_to::<u32>(sp2), true));
+fn parse_hand(line: &str, with_joker: bool) -> Result<Hand> {
+    let (cards, bid) = line
+        .split_once(' ')
+        .ok_or_else(|| Error::parse("cards and a bid", line))?;
+    let bid = parse_token(line, bid, "bid")?;
+    Hand::from_inputs(cards, bid, with_joker).map_err(|err| err.within(line, cards))
+}
+
+fn total_winnings(input: &str, with_joker: bool) -> Result<usize> {
+    let mut hands = parse_lines(input, |line| parse_hand(line, with_joker))?;
+    hands.sort();
+    Ok(hands
+        .iter()
+        .enumerate()
+        .map(|(idx, hand)| (idx + 1) * hand.bid as usize)
+        .sum())
+}
+
+impl Solution for Day07 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(total_winnings(input, false)?.into())
     }
-    hands_p1.sort();
-    hands_p2.sort();
-    let mut res_p1 = 0;
-    let mut res_p2 = 0;
-    for idx in 0..hands_p1.len() {
-        res_p1 += (idx + 1) * hands_p1[idx].bid as usize;
-        res_p2 += (idx + 1) * hands_p2[idx].bid as usize;
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(total_winnings(input, true)?.into())
     }
-    //250898830, 252127335
-    (res_p1 as u32, res_p2 as u32)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_part1() {
+        let input: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let result = Day07.part1(input);
+        assert_eq!(result, Ok(6440.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let result = Day07.part2(input);
+        assert_eq!(result, Ok(5905.into()));
+    }
+
     #[test]
     fn hand_type_sort_test() {
         assert!(HandType::FiveOfKind > HandType::FourOfKind);
@@ -139,154 +176,176 @@ mod tests {
     #[test]
     fn get_cards_array_test() {
         // no joker
-        assert_eq!(Hand::get_cards_array("23456", false), [2, 3, 4, 5, 6]);
-        assert_eq!(Hand::get_cards_array("TJQKA", false), [10, 11, 12, 13, 14]);
+        assert_eq!(Hand::get_cards_array("23456", false), Ok([2, 3, 4, 5, 6]));
+        assert_eq!(
+            Hand::get_cards_array("TJQKA", false),
+            Ok([10, 11, 12, 13, 14])
+        );
 
         // with joker
-        assert_eq!(Hand::get_cards_array("TJQKA", true), [10, 1, 11, 12, 13]);
+        assert_eq!(
+            Hand::get_cards_array("TJQKA", true),
+            Ok([10, 1, 11, 12, 13])
+        );
     }
     #[test]
     fn comp_hands_test() {
-        assert!(Hand::from_inputs(&"JKKK2", 1, true) < Hand::from_inputs(&"QQQQ2", 1, true));
-        assert!(Hand::from_inputs(&"T55J5", 1, true) < Hand::from_inputs(&"QQQJA", 1, true));
-        assert!(Hand::from_inputs(&"QQQJA", 1, true) < Hand::from_inputs(&"KTJJT", 1, true));
+        let hand = |cards| Hand::from_inputs(cards, 1, true).unwrap();
+        assert!(hand("JKKK2") < hand("QQQQ2"));
+        assert!(hand("T55J5") < hand("QQQJA"));
+        assert!(hand("QQQJA") < hand("KTJJT"));
 
-        assert!(Hand::from_inputs(&"KKK23", 1, true) < Hand::from_inputs(&"AJJ94", 1, true));
-        assert!(Hand::from_inputs(&"AJJ94", 1, true) < Hand::from_inputs(&"A2223", 1, true));
+        assert!(hand("KKK23") < hand("AJJ94"));
+        assert!(hand("AJJ94") < hand("A2223"));
 
-        assert!(Hand::from_inputs(&"JJJJJ", 1, true) < Hand::from_inputs(&"2JJJJ", 1, true));
+        assert!(hand("JJJJJ") < hand("2JJJJ"));
     }
     #[test]
     fn calc_hand_type_test() {
         // no joker
         assert_eq!(Hand::calc_hand_type([6, 6, 6, 6, 6]), HandType::FiveOfKind);
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("AAAAA", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("AAAAA", false).unwrap()),
             HandType::FiveOfKind
         );
 
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("AAQQQ", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("AAQQQ", false).unwrap()),
             HandType::FullHouse
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("23332", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("23332", false).unwrap()),
             HandType::FullHouse
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("43434", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("43434", false).unwrap()),
             HandType::FullHouse
         );
 
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("43442", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("43442", false).unwrap()),
             HandType::ThreeOfKind
         );
 
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("43432", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("43432", false).unwrap()),
             HandType::TwoPair
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("AQAQ2", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("AQAQ2", false).unwrap()),
             HandType::TwoPair
         );
 
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("44532", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("44532", false).unwrap()),
             HandType::OnePair
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("4A53A", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("4A53A", false).unwrap()),
             HandType::OnePair
         );
 
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("A2345", false)),
+            Hand::calc_hand_type(Hand::get_cards_array("A2345", false).unwrap()),
             HandType::HighCard
         );
 
         // with joker
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("AAJAA", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("AAJAA", true).unwrap()),
             HandType::FiveOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("AJJAA", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("AJJAA", true).unwrap()),
             HandType::FiveOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("A3JAA", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("A3JAA", true).unwrap()),
             HandType::FourOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("JAA45", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("JAA45", true).unwrap()),
             HandType::ThreeOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("2345J", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("2345J", true).unwrap()),
             HandType::OnePair
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("2342J", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("2342J", true).unwrap()),
             HandType::ThreeOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("23J2J", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("23J2J", true).unwrap()),
             HandType::FourOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("23J2J", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("23J2J", true).unwrap()),
             HandType::FourOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("JJJJJ", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("JJJJJ", true).unwrap()),
             HandType::FiveOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("J4JJJ", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("J4JJJ", true).unwrap()),
             HandType::FiveOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("QQQQ2", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("QQQQ2", true).unwrap()),
             HandType::FourOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("JKKK2", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("JKKK2", true).unwrap()),
             HandType::FourOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("J2K77", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("J2K77", true).unwrap()),
             HandType::ThreeOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("JJ423", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("JJ423", true).unwrap()),
             HandType::ThreeOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("QQKKJ", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("QQKKJ", true).unwrap()),
             HandType::FullHouse
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("AJJ94", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("AJJ94", true).unwrap()),
             HandType::ThreeOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("2233J", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("2233J", true).unwrap()),
             HandType::FullHouse
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("4T48J", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("4T48J", true).unwrap()),
             HandType::ThreeOfKind
         );
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("J2345", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("J2345", true).unwrap()),
             HandType::OnePair
         );
         // FUCK!
         assert_eq!(
-            Hand::calc_hand_type(Hand::get_cards_array("JJJ34", true)),
+            Hand::calc_hand_type(Hand::get_cards_array("JJJ34", true).unwrap()),
             HandType::FourOfKind
         );
     }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day07.part1("32T3K 765\nT55J5 lots").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 7: expected bid, found \"lots\""
+        );
+        let err = Day07.part2("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected a card, found \"X\""
+        );
+        assert!(Day07.part1("32T3 765").is_err());
+    }
 }
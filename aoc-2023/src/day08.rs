@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use aoc_core::utils::{self, math};
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day08;
+register!(2023, 8, Day08);
+
+type Map = HashMap<String, (String, String)>;
+
+/// Steps from `start_node` to one of `possible_end_nodes`, following the
+/// directions from the `offset`th one on.
+fn traverse_graph<'a>(
+    directions: &str,
+    map: &'a Map,
+    start_node: &'a String,
+    possible_end_nodes: &Vec<&String>,
+    offset: u128,
+) -> Result<(u128, &'a String)> {
+    let mut res = 0;
+    let mut curr = start_node;
+    let offset = (offset % directions.len() as u128) as usize;
+    for dir in directions.chars().cycle().skip(offset) {
+        let curr_dirs = map
+            .get(curr)
+            .ok_or_else(|| Error::input(format!("node {} isn't on the map", curr)))?;
+        // `parse` only lets through `L` and `R`.
+        curr = if dir == 'L' {
+            &curr_dirs.0
+        } else {
+            &curr_dirs.1
+        };
+        res += 1;
+        if possible_end_nodes.contains(&curr) {
+            break;
+        }
+    }
+    Ok((res, curr))
+}
+
+/// Each ghost reaches an end node first after some steps, and then keeps
+/// coming back to it with a fixed period, which needn't be the same number
+/// of steps. All ghosts are at an end node at the first step at least as
+/// late as every first arrival that fits all periods.
+fn solve_p2(directions: &str, map: &Map) -> Result<i128> {
+    let start_nodes: Vec<&String> = map.keys().filter(|k| k.ends_with('A')).collect();
+    let end_nodes: Vec<&String> = map.keys().filter(|k| k.ends_with('Z')).collect();
+    let mut cycles = Vec::new();
+    for start in start_nodes {
+        let (first, end) = traverse_graph(directions, map, start, &end_nodes, 0)?;
+        let (period, _) = traverse_graph(directions, map, end, &end_nodes, first)?;
+        cycles.push((first as i128, period as i128));
+    }
+    let (res, period) = math::crt(cycles.iter().map(|&(first, period)| (first, period)))?;
+    let latest = cycles.iter().map(|&(first, _)| first).max().unwrap_or(0);
+    let periods_behind = (latest - res).max(0);
+    Ok(res + (periods_behind + period - 1) / period * period)
+}
+
+fn parse_node(line: &str) -> Result<(String, (String, String))> {
+    let (src, dirs) = line
+        .split_once('=')
+        .ok_or_else(|| Error::parse("`NODE = (LEFT, RIGHT)`", line))?;
+    let dirs = dirs.trim();
+    let (dir_left, dir_right) = dirs
+        .strip_prefix('(')
+        .and_then(|dirs| dirs.strip_suffix(')'))
+        .and_then(|dirs| dirs.split_once(','))
+        .ok_or_else(|| Error::parse_at(line, dirs, "`(LEFT, RIGHT)`"))?;
+    Ok((
+        src.trim().to_owned(),
+        (dir_left.trim().to_owned(), dir_right.trim().to_owned()),
+    ))
+}
+
+fn parse(input: &str) -> Result<(String, Map)> {
+    let mut blocks = utils::blocks(input);
+    let directions = blocks.next().unwrap_or_default();
+    if directions.is_empty() {
+        return Err(Error::input("no directions"));
+    }
+    if let Some((idx, c)) = directions
+        .char_indices()
+        .find(|&(_, c)| c != 'L' && c != 'R')
+    {
+        let token = &directions[idx..idx + c.len_utf8()];
+        return Err(Error::parse_at(input, token, "`L` or `R`"));
+    }
+    let nodes = blocks.next().unwrap_or_default();
+    let map = utils::parse_lines(nodes, parse_node)
+        .map_err(|err| err.within(input, nodes))?
+        .into_iter()
+        .collect();
+    Ok((directions.to_owned(), map))
+}
+
+impl Solution for Day08 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let (directions, map) = parse(input)?;
+        let (res_p1, _) = traverse_graph(
+            &directions,
+            &map,
+            &"AAA".to_owned(),
+            &vec![&"ZZZ".to_owned()],
+            0,
+        )?;
+        Ok(res_p1.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let (directions, map) = parse(input)?;
+        Ok(solve_p2(&directions, &map)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        let result = Day08.part1(input);
+        assert_eq!(result, Ok(2.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let result = Day08.part2(input);
+        assert_eq!(result, Ok(6.into()));
+    }
+
+    #[test]
+    fn test_part2_offset_cycles() {
+        // The first ghost is at its end after 2, 4, 6, ... steps, the second
+        // one after 1, 4, 7, ...
+        let input: &str = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)";
+        let result = Day08.part2(input);
+        assert_eq!(result, Ok(4.into()));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day08.part1("LXR\n\nAAA = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 2: expected `L` or `R`, found \"X\""
+        );
+        let err = Day08.part1("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = ZZZ").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 7: expected `(LEFT, RIGHT)`, found \"ZZZ\""
+        );
+        let err = Day08.part1("L\n\nAAA = (BBB, BBB)").unwrap_err();
+        assert_eq!(err.to_string(), "node BBB isn't on the map");
+    }
+}
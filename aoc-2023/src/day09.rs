@@ -0,0 +1,97 @@
+use aoc_core::utils::*;
+use aoc_core::{register, Answer, Result, Solution};
+
+pub struct Day09;
+register!(2023, 9, Day09);
+
+fn get_difference_array(seq: &[i64]) -> Vec<i64> {
+    let res_len = seq.len() - 1;
+    (0..res_len).fold(Vec::with_capacity(res_len), |mut acc, idx| {
+        acc.push(seq[idx + 1] - seq[idx]);
+        acc
+    })
+}
+
+fn extrapolate_sequence(seq: &[i64]) -> (i64, i64) {
+    if seq.iter().all(|&el| el == 0) {
+        return (0, 0);
+    }
+    let diff_array = get_difference_array(seq);
+    let (ex_front, ex_back) = extrapolate_sequence(&diff_array);
+
+    let extrapolate_front = seq.iter().next().unwrap() - ex_front;
+    let extrapolate_back = seq.iter().last().unwrap() + ex_back;
+    (extrapolate_front, extrapolate_back)
+}
+
+fn parse_sequence(line: &str) -> Result<Vec<i64>> {
+    line.split_whitespace()
+        .map(|n| parse_token(line, n, "integer"))
+        .collect()
+}
+
+impl Solution for Day09 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let sequences = parse_lines(input, parse_sequence)?;
+        let res_p1: i64 = sequences
+            .iter()
+            .map(|seq| extrapolate_sequence(seq).1)
+            .sum();
+        Ok(res_p1.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let sequences = parse_lines(input, parse_sequence)?;
+        let res_p2: i64 = sequences
+            .iter()
+            .map(|seq| extrapolate_sequence(seq).0)
+            .sum();
+        Ok(res_p2.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let result = Day09.part1(input);
+        assert_eq!(result, Ok(114.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let result = Day09.part2(input);
+        assert_eq!(result, Ok(2.into()));
+    }
+
+    #[test]
+    fn get_difference_array_test() {
+        assert_eq!(
+            get_difference_array(&[0, 3, 6, 9, 12, 15]),
+            vec![3, 3, 3, 3, 3]
+        );
+    }
+    #[test]
+    fn extrapolate_sequence_test() {
+        assert_eq!(extrapolate_sequence(&[0, 3, 6, 9, 12, 15]), (-3, 18));
+        assert_eq!(extrapolate_sequence(&[1, 3, 6, 10, 15, 21]), (0, 28));
+        assert_eq!(extrapolate_sequence(&[10, 13, 16, 21, 30, 45]), (5, 68));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day09.part1("0 3 6\n1 x 6").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected integer, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_core::utils::{Coord, Direction, Grid};
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day10;
+register!(2023, 10, Day10);
+
+type Map = Grid<Option<Pipe>>;
+
+#[derive(Debug, Clone)]
+struct Pipe {
+    connections: [Direction; 2],
+}
+
+impl Pipe {
+    fn from_char(c: char) -> Option<Self> {
+        use Direction::{Down, Left, Right, Up};
+        match c {
+            '|' => Some(Pipe {
+                connections: [Up, Down],
+            }),
+            '-' => Some(Pipe {
+                connections: [Right, Left],
+            }),
+            'L' => Some(Pipe {
+                connections: [Up, Right],
+            }),
+            'J' => Some(Pipe {
+                connections: [Up, Left],
+            }),
+            '7' => Some(Pipe {
+                connections: [Down, Left],
+            }),
+            'F' => Some(Pipe {
+                connections: [Down, Right],
+            }),
+            _ => None,
+        }
+    }
+
+    fn get_the_other_dir(&self, came_from: Direction) -> Direction {
+        if self.connections[0] == came_from {
+            self.connections[1]
+        } else {
+            self.connections[0]
+        }
+    }
+}
+
+/// Any direction from `start` into a pipe that connects back to it.
+fn find_start_dir(map: &Map, start: Coord) -> Result<Direction> {
+    Direction::ALL
+        .into_iter()
+        .find(|dir| {
+            map.get(start + dir.to_coord())
+                .and_then(Option::as_ref)
+                .is_some_and(|pipe| pipe.connections.contains(&dir.opposite()))
+        })
+        .ok_or_else(|| Error::solve("no pipe connects to the start"))
+}
+
+fn loop_length(input: &str) -> Result<u32> {
+    let tiles = Grid::parse(input, Ok)?;
+    let start_pos = tiles
+        .find(&'S')
+        .ok_or_else(|| Error::parse("a start `S`", "none"))?;
+    let map: Map = tiles.map(|&c| Pipe::from_char(c));
+    let mut loop_counter = 0;
+    let mut curr_pos = start_pos;
+    let mut curr_dir = find_start_dir(&map, start_pos)?;
+    loop {
+        let next_pos = curr_pos + curr_dir.to_coord();
+        if next_pos == start_pos {
+            break;
+        }
+        let next_tube = map
+            .get(next_pos)
+            .and_then(Option::as_ref)
+            .ok_or_else(|| Error::solve("the loop from the start is broken"))?;
+        loop_counter += 1;
+
+        curr_dir = next_tube.get_the_other_dir(curr_dir.opposite());
+        curr_pos = next_pos;
+    }
+
+    Ok(loop_counter + 1)
+}
+
+impl Solution for Day10 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok((loop_length(input)? / 2).into())
+    }
+
+    fn part2(&self, _input: &str) -> Result<Answer> {
+        unimplemented!("Part 2 not implemented yet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+        let result = Day10.part1(input);
+        assert_eq!(result, Ok(8.into()));
+    }
+}
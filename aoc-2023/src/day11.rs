@@ -1,4 +1,16 @@
-use crate::utils::*;
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day11 {
+    /// How many times larger empty rows and columns are in part 2.
+    expansion: usize,
+}
+register!(2023, 11, Day11::PUZZLE);
+
+impl Day11 {
+    pub const PUZZLE: Day11 = Day11 {
+        expansion: 1_000_000,
+    };
+}
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 struct Point {
@@ -6,21 +18,15 @@ struct Point {
     y: usize, // vertical   ↓
 }
 
-impl Point {
-    fn zero() -> Self {
-        Point { x: 0, y: 0 }
-    }
-}
-
 fn get_distance(
     p1: Point,
     p2: Point,
-    x_expansions: &Vec<usize>,
-    y_expansions: &Vec<usize>,
+    x_expansions: &[usize],
+    y_expansions: &[usize],
     multiplier: usize,
 ) -> usize {
-    let x_dist = (p1.x as i32 - p2.x as i32).abs() as usize;
-    let y_dist = (p1.y as i32 - p2.y as i32).abs() as usize;
+    let x_dist = (p1.x as i32 - p2.x as i32).unsigned_abs() as usize;
+    let y_dist = (p1.y as i32 - p2.y as i32).unsigned_abs() as usize;
 
     let x_expands = x_expansions
         .iter()
@@ -38,15 +44,15 @@ fn generate_unique_pairs(points: &[Point]) -> Vec<(Point, Point)> {
     let mut pairs = Vec::new();
     for i in 0..points.len() {
         for j in (i + 1)..points.len() {
-            pairs.push((points[i].clone(), points[j].clone()));
+            pairs.push((points[i], points[j]));
         }
     }
     pairs
 }
 
-pub fn solve_d11() -> (usize, usize) {
+fn sum_of_distances(input: &str, multiplier: usize) -> usize {
     // load the universe
-    let lines = read_lines("data/d11.txt");
+    let lines: Vec<&str> = input.lines().collect();
     let n_rows = lines.len();
     assert!(n_rows > 0);
     let n_cols = lines[0].len();
@@ -84,71 +90,92 @@ pub fn solve_d11() -> (usize, usize) {
     let pairs = generate_unique_pairs(&galaxy_points);
 
     // calculate their manhattan distances
-    let mut res_p1 = 0;
-    let mut res_p2 = 0;
-    for pair in pairs {
-        res_p1 += get_distance(pair.0, pair.1, &x_expansions, &y_expansions, 2);
-        res_p2 += get_distance(pair.0, pair.1, &x_expansions, &y_expansions, 1_000_000);
+    pairs
+        .into_iter()
+        .map(|pair| get_distance(pair.0, pair.1, &x_expansions, &y_expansions, multiplier))
+        .sum()
+}
+
+impl Solution for Day11 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(sum_of_distances(input, 2).into())
     }
 
-    //10490062, 382979724122
-    (res_p1, res_p2)
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(sum_of_distances(input, self.expansion).into())
+    }
+
+    fn with_params(&self, params: &aoc_core::Params) -> Option<Result<Box<dyn Solution>>> {
+        let mut expansion = self.expansion;
+        let configured = params
+            .check_keys(&["expansion"])
+            .and_then(|_| params.apply("expansion", &mut expansion))
+            .and_then(|_| match expansion {
+                0 => Err(Error::input("the expansion must be positive")),
+                _ => Ok(Box::new(Day11 { expansion }) as Box<dyn Solution>),
+            });
+        Some(configured)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_part1() {
+        let input: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+        let result = Day11::PUZZLE.part1(input);
+        assert_eq!(result, Ok(374.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+        let result = Day11::PUZZLE.part2(input);
+        assert_eq!(result, Ok(82000210.into()));
+        assert_eq!(Day11 { expansion: 10 }.part2(input), Ok(1030.into()));
+        assert_eq!(Day11 { expansion: 100 }.part2(input), Ok(8410.into()));
+    }
+
     #[test]
     fn get_distance_test() {
         assert_eq!(
-            get_distance(
-                Point { x: 0, y: 0 },
-                Point { x: 4, y: 0 },
-                &vec![],
-                &vec![],
-                2
-            ),
+            get_distance(Point { x: 0, y: 0 }, Point { x: 4, y: 0 }, &[], &[], 2),
             4
         );
         assert_eq!(
-            get_distance(
-                Point { x: 5, y: 0 },
-                Point { x: 0, y: 0 },
-                &vec![],
-                &vec![],
-                2
-            ),
+            get_distance(Point { x: 5, y: 0 }, Point { x: 0, y: 0 }, &[], &[], 2),
             5
         );
         assert_eq!(
-            get_distance(
-                Point { x: 1, y: 1 },
-                Point { x: 3, y: 3 },
-                &vec![],
-                &vec![],
-                2
-            ),
+            get_distance(Point { x: 1, y: 1 }, Point { x: 3, y: 3 }, &[], &[], 2),
             4
         );
         assert_eq!(
-            get_distance(
-                Point { x: 1, y: 1 },
-                Point { x: 3, y: 3 },
-                &vec![2],
-                &vec![],
-                2
-            ),
+            get_distance(Point { x: 1, y: 1 }, Point { x: 3, y: 3 }, &[2], &[], 2),
             5
         );
         assert_eq!(
-            get_distance(
-                Point { x: 1, y: 1 },
-                Point { x: 3, y: 3 },
-                &vec![2],
-                &vec![2],
-                2
-            ),
+            get_distance(Point { x: 1, y: 1 }, Point { x: 3, y: 3 }, &[2], &[2], 2),
             6
         );
 
@@ -156,8 +183,8 @@ mod tests {
             get_distance(
                 Point { x: 3, y: 0 },
                 Point { x: 7, y: 8 },
-                &vec![2, 5, 8],
-                &vec![3, 7],
+                &[2, 5, 8],
+                &[3, 7],
                 2
             ),
             15
@@ -167,8 +194,8 @@ mod tests {
             get_distance(
                 Point { x: 1, y: 5 },
                 Point { x: 4, y: 9 },
-                &vec![2, 5, 8],
-                &vec![3, 7],
+                &[2, 5, 8],
+                &[3, 7],
                 2
             ),
             9
@@ -178,20 +205,8 @@ mod tests {
     #[test]
     fn get_distance_order_test() {
         assert_eq!(
-            get_distance(
-                Point { x: 4, y: 0 },
-                Point { x: 0, y: 0 },
-                &vec![],
-                &vec![],
-                2
-            ),
-            get_distance(
-                Point { x: 0, y: 0 },
-                Point { x: 4, y: 0 },
-                &vec![],
-                &vec![],
-                2
-            ),
+            get_distance(Point { x: 4, y: 0 }, Point { x: 0, y: 0 }, &[], &[], 2),
+            get_distance(Point { x: 0, y: 0 }, Point { x: 4, y: 0 }, &[], &[], 2),
         );
         // assert_eq!(
         //     get_distance(
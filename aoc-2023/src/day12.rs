@@ -0,0 +1,140 @@
+use aoc_core::utils::memo::Memo;
+use aoc_core::utils::*;
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day12;
+register!(2023, 12, Day12);
+
+/// Part 2 unfolds each row into this many copies.
+const COPIES: usize = 5;
+
+#[cfg(test)]
+fn count_spring_groups(s: &str) -> Vec<usize> {
+    s.split('.')
+        .filter(|el| !el.is_empty())
+        .map(|el| el.len())
+        .collect()
+}
+
+/// Number of ways to fill in the `?` from `springs[pos..]` so that the
+/// damaged springs `#` form the runs `groups[group..]`.
+fn arrangements(
+    springs: &[char],
+    groups: &[usize],
+    pos: usize,
+    group: usize,
+    memo: &mut Memo<(usize, usize), u64>,
+) -> u64 {
+    if pos >= springs.len() {
+        return (group == groups.len()) as u64;
+    }
+    memo.call((pos, group), |memo| {
+        let mut count = 0;
+        if springs[pos] != '#' {
+            count += arrangements(springs, groups, pos + 1, group, memo);
+        }
+        // The next run starts here: it has to fit, and end before a spring
+        // that isn't damaged.
+        if let Some(&len) = groups.get(group).filter(|_| springs[pos] != '.') {
+            let end = pos + len;
+            if end <= springs.len()
+                && !springs[pos..end].contains(&'.')
+                && springs.get(end) != Some(&'#')
+            {
+                count += arrangements(springs, groups, end + 1, group + 1, memo);
+            }
+        }
+        count
+    })
+}
+
+/// Number of arrangements of a row like `???.### 1,1,3`, unfolded into
+/// `copies` copies joined by `?`.
+fn solve_spring_map(line: &str, copies: usize) -> Result<u64> {
+    let (springs, groups) = line
+        .split_once(' ')
+        .ok_or_else(|| Error::parse("springs and group sizes", line))?;
+    if let Some((idx, c)) = springs.char_indices().find(|&(_, c)| !".#?".contains(c)) {
+        let token = &springs[idx..idx + c.len_utf8()];
+        return Err(Error::parse_at(line, token, "`.`, `#` or `?`"));
+    }
+    let groups: Vec<usize> = integers(groups).map_err(|err| err.within(line, groups))?;
+
+    let springs: Vec<char> = vec![springs; copies].join("?").chars().collect();
+    let groups = groups.repeat(copies);
+    Ok(arrangements(&springs, &groups, 0, 0, &mut Memo::new()))
+}
+
+impl Solution for Day12 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let counts = parse_lines(input, |line| solve_spring_map(line, 1))?;
+        Ok(counts.iter().sum::<u64>().into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let counts = parse_lines(input, |line| solve_spring_map(line, COPIES))?;
+        Ok(counts.iter().sum::<u64>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        let result = Day12.part1(input);
+        assert_eq!(result, Ok(21.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        let result = Day12.part2(input);
+        assert_eq!(result, Ok(525152.into()));
+        assert_eq!(solve_spring_map(".??..??...?##. 1,1,3", COPIES), Ok(16384));
+        assert_eq!(solve_spring_map("?###???????? 3,2,1", COPIES), Ok(506250));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day12.part1("???.### 1,1,3\n??x 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected `.`, `#` or `?`, found \"x\""
+        );
+        assert!(Day12.part1("???.###").is_err());
+    }
+
+    #[test]
+    fn count_spring_groups_test() {
+        assert_eq!(count_spring_groups("#.#.###"), vec![1, 1, 3]);
+        assert_eq!(count_spring_groups(".#.###.#.######"), vec![1, 3, 1, 6]);
+        assert_eq!(count_spring_groups("####.#...#..."), vec![4, 1, 1]);
+        assert_eq!(count_spring_groups("#....######..#####."), vec![1, 6, 5]);
+        assert_eq!(count_spring_groups(".###.##....#"), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn solve_spring_map_test() {
+        assert_eq!(solve_spring_map("??? 1,1", 1), Ok(1));
+
+        assert_eq!(solve_spring_map("???.### 1,1,3", 1), Ok(1));
+        assert_eq!(solve_spring_map(".??..??...?##. 1,1,3", 1), Ok(4));
+        assert_eq!(solve_spring_map("?#?#?#?#?#?#?#? 1,3,1,6", 1), Ok(1));
+        assert_eq!(solve_spring_map("????.#...#... 4,1,1", 1), Ok(1));
+        assert_eq!(solve_spring_map("????.######..#####. 1,6,5", 1), Ok(4));
+        assert_eq!(solve_spring_map("?###???????? 3,2,1", 1), Ok(10));
+    }
+}
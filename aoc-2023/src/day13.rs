@@ -0,0 +1,154 @@
+use aoc_core::utils::{self, Grid};
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day13;
+register!(2023, 13, Day13);
+
+type Map = Grid<char>;
+
+fn get_other_terrain(t: char) -> char {
+    match t {
+        '.' => '#',
+        '#' => '.',
+        _ => t,
+    }
+}
+
+/// Returns true if mirror is found at indices ((pos-1), pos)
+fn is_mirror_at_pos<T: PartialEq>(line: &[T], pos: usize) -> bool {
+    if pos == 0 || pos >= line.len() {
+        return false;
+    }
+    (pos..line.len())
+        .zip((0..pos).rev())
+        .all(|(b, f)| line[f] == line[b])
+}
+
+/// The first row above which `map` is mirrored, as its index times
+/// `factor`, other than `skip`.
+fn scan_rows(map: &Map, factor: usize, skip: Option<usize>) -> Option<usize> {
+    let rows: Vec<&[char]> = map.rows().collect();
+    (0..rows.len())
+        .filter(|&idx| is_mirror_at_pos(&rows, idx))
+        .map(|idx| factor * idx)
+        .find(|&res| skip != Some(res))
+}
+
+fn scan_mirrors(map: &Map, skip: Option<usize>) -> Option<usize> {
+    assert!(map.width() > 0 && map.height() > 0);
+    // scan vertical direction, i.e. by rows, then horizontal, i.e. by columns
+    scan_rows(map, 100, skip).or_else(|| scan_rows(&map.transpose(), 1, skip))
+}
+
+fn scan_mirrors_p2(map: &Map, skip: Option<usize>) -> Option<usize> {
+    let mut map_clone = map.clone();
+    for pos in map.coords() {
+        map_clone[pos] = get_other_terrain(map[pos]);
+        if let Some(d) = scan_mirrors(&map_clone, skip) {
+            return Some(d);
+        }
+        map_clone[pos] = map[pos];
+    }
+    None
+}
+
+fn parse_maps(input: &str) -> Result<Vec<Map>> {
+    utils::parse_blocks(input, |block| Grid::parse(block, Ok))
+}
+
+fn no_mirror() -> Error {
+    Error::solve("a pattern without a line of reflection")
+}
+
+impl Solution for Day13 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let mut res_p1 = 0;
+        for map in parse_maps(input)? {
+            res_p1 += scan_mirrors(&map, None).ok_or_else(no_mirror)?;
+        }
+        Ok(res_p1.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let mut res_p2 = 0;
+        for map in parse_maps(input)? {
+            let original = scan_mirrors(&map, None);
+            res_p2 += scan_mirrors_p2(&map, original).ok_or_else(no_mirror)?;
+        }
+        Ok(res_p2.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        let result = Day13.part1(input);
+        assert_eq!(result, Ok(405.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        let result = Day13.part2(input);
+        assert_eq!(result, Ok(400.into()));
+    }
+
+    fn to_vec(s: &str) -> Vec<char> {
+        s.chars().collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn is_mirror_at_pos_test() {
+        assert!(is_mirror_at_pos(&to_vec("##"), 1));
+        assert!(!is_mirror_at_pos(&to_vec("##"), 0));
+        assert!(!is_mirror_at_pos(&to_vec("##"), 2));
+
+        assert!(is_mirror_at_pos(&to_vec("#..#"), 2));
+        assert!(!is_mirror_at_pos(&to_vec("#..#"), 1));
+        assert!(!is_mirror_at_pos(&to_vec("#..#"), 0));
+
+        assert!(is_mirror_at_pos(&to_vec("#.##..##."), 5));
+        assert!(!is_mirror_at_pos(&to_vec("#.##..##."), 4));
+    }
+
+    #[test]
+    fn is_vert_mirror_at_pos_test() {
+        assert!(is_mirror_at_pos(&[&to_vec("###..."), &to_vec("###...")], 1));
+        assert!(!is_mirror_at_pos(
+            &["#.#...".to_owned(), "###...".to_owned()],
+            1
+        ));
+    }
+}
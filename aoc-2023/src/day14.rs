@@ -0,0 +1,130 @@
+use aoc_core::utils::cycle::Simulate;
+use aoc_core::utils::{Coord, Grid};
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day14;
+register!(2023, 14, Day14);
+
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+/// The platform with round rocks `O` that roll and cube rocks `#` that
+/// don't, turned so that north is up.
+#[derive(Clone, Debug)]
+struct Platform {
+    grid: Grid<char>,
+}
+
+impl Platform {
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse(input, |c| match c {
+            'O' | '#' | '.' => Ok(c),
+            _ => Err(Error::parse("`O`, `#` or `.`", c.to_string())),
+        })?;
+        Ok(Self { grid })
+    }
+
+    /// Rolls all round rocks as far up as they go.
+    fn tilt_north(&mut self) {
+        for x in 0..self.grid.width() as i32 {
+            let mut free = 0;
+            for y in 0..self.grid.height() as i32 {
+                let pos = Coord { x, y };
+                match self.grid[pos] {
+                    '#' => free = y + 1,
+                    'O' => {
+                        self.grid[pos] = '.';
+                        self.grid[Coord { x, y: free }] = 'O';
+                        free += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Sum of the rows of all round rocks, counted from the bottom.
+    fn load(&self) -> usize {
+        self.grid
+            .find_all(&'O')
+            .map(|pos| self.grid.height() - pos.y as usize)
+            .sum()
+    }
+}
+
+/// A spin cycle tilts north, west, south and east. Turning the platform
+/// clockwise after each tilt brings the next side up, and north back up
+/// after all four.
+impl Simulate for Platform {
+    type Fingerprint = Grid<char>;
+
+    fn step(&mut self) {
+        for _ in 0..4 {
+            self.tilt_north();
+            self.grid = self.grid.rotate_right();
+        }
+    }
+
+    fn fingerprint(&self) -> Grid<char> {
+        self.grid.clone()
+    }
+}
+
+impl Solution for Day14 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let mut platform = Platform::parse(input)?;
+        platform.tilt_north();
+        Ok(platform.load().into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(Platform::parse(input)?.after(SPIN_CYCLES).load().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_part1() {
+        let result = Day14.part1(INPUT);
+        assert_eq!(result, Ok(136.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = Day14.part2(INPUT);
+        assert_eq!(result, Ok(64.into()));
+    }
+
+    #[test]
+    fn test_spin_cycle() {
+        let platform = Platform::parse(INPUT).unwrap().after(1);
+        assert_eq!(
+            platform.grid.to_string(),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+        );
+        let cycle = Platform::parse(INPUT).unwrap().find_cycle();
+        assert_eq!((cycle.start, cycle.length), (3, 7));
+    }
+}
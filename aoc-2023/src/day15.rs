@@ -0,0 +1,131 @@
+use aoc_core::utils::*;
+use aoc_core::{register, Answer, Error, Result, Solution};
+
+pub struct Day15;
+register!(2023, 15, Day15);
+
+#[derive(Debug, Hash, Clone)]
+struct Box<'a> {
+    lenses: Vec<Lens<'a>>,
+}
+
+impl<'a> Box<'a> {
+    fn new() -> Self {
+        Self { lenses: Vec::new() }
+    }
+}
+#[derive(Debug, Hash, Clone)]
+struct Lens<'a> {
+    label: &'a str,
+    len: u8,
+}
+
+impl<'a> Lens<'a> {
+    fn new(label: &'a str, len: u8) -> Self {
+        Self { label, len }
+    }
+}
+
+fn hash(s: &str) -> u8 {
+    let mut res: u16 = 0;
+    for c in s.chars() {
+        let ascii = c as u8;
+        res += ascii as u16;
+        res *= 17;
+        res %= 256;
+    }
+    res as u8
+}
+
+fn focusing_power(input: &str) -> Result<usize> {
+    let mut boxes = vec![Box::new(); 256];
+    for command in input.trim().split(',') {
+        let Some((lens_label, cmd_part2)) = command.split_once(['=', '-']) else {
+            return Err(Error::parse_at(
+                input,
+                command,
+                "a step `label=length` or `label-`",
+            ));
+        };
+        let box_id = hash(lens_label);
+
+        let box_in_question = &mut boxes[box_id as usize];
+        if cmd_part2.is_empty() {
+            // erase operation
+            box_in_question.lenses.retain(|b| b.label != lens_label);
+        } else {
+            // add operation
+            let lens_len = parse_token(input, cmd_part2, "focal length")?;
+            let new_lens = Lens::new(lens_label, lens_len);
+            if let Some(existing_lens) = box_in_question
+                .lenses
+                .iter_mut()
+                .find(|lens| lens.label == lens_label)
+            {
+                existing_lens.len = lens_len;
+            } else {
+                box_in_question.lenses.push(new_lens);
+            }
+        }
+    }
+    let mut res_p2 = 0;
+    for (i, b) in boxes.iter().enumerate() {
+        for (ii, lens) in b.lenses.iter().enumerate() {
+            res_p2 += (i + 1) * (ii + 1) * lens.len as usize;
+        }
+    }
+    Ok(res_p2)
+}
+
+impl Solution for Day15 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let res_p1: usize = input.trim().split(',').map(|cmd| hash(cmd) as usize).sum();
+        Ok(res_p1.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(focusing_power(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let result = Day15.part1(input);
+        assert_eq!(result, Ok(1320.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let result = Day15.part2(input);
+        assert_eq!(result, Ok(145.into()));
+    }
+
+    #[test]
+    fn hash_test() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("cm-"), 253);
+        assert_eq!(hash("qp=3"), 97);
+        assert_eq!(hash("cm=2"), 47);
+
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("cm"), 0);
+        assert_eq!(hash("qp"), 1);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day15.part2("rn=1,cm=x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 9: expected focal length, found \"x\""
+        );
+        assert!(Day15.part2("rn=1,cm").is_err());
+    }
+}
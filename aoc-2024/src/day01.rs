@@ -1,37 +1,41 @@
-use crate::{utils, Solution};
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
 
 pub struct Day01;
+register!(2024, 1, Day01);
+
+fn parse_pair(line: &str) -> Result<(i32, i32)> {
+    match utils::parse_numbers_from_string(line)?[..] {
+        [left, right] => Ok((left, right)),
+        _ => Err(Error::parse("two numbers", line)),
+    }
+}
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> String {
-        let (mut v1, mut v2): (Vec<i32>, Vec<i32>) = input
-            .lines()
-            .map(utils::parse_numbers_from_string)
-            .map(|nums| (nums[0], nums[1]))
-            .unzip();
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let (mut v1, mut v2): (Vec<i32>, Vec<i32>) =
+            utils::parse_lines(input, parse_pair)?.into_iter().unzip();
         v1.sort();
         v2.sort();
 
-        v1.iter()
+        Ok(v1
+            .iter()
             .zip(v2.iter())
             .map(|(l, r)| (r - l).abs())
             .sum::<i32>()
-            .to_string()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let (v1, v2): (Vec<i32>, Vec<i32>) = input
-            .lines()
-            .map(utils::parse_numbers_from_string)
-            .map(|nums| (nums[0], nums[1]))
-            .unzip();
-        v1.iter()
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let (v1, v2): (Vec<i32>, Vec<i32>) =
+            utils::parse_lines(input, parse_pair)?.into_iter().unzip();
+        Ok(v1
+            .iter()
             .map(|x| {
                 let count = v2.iter().filter(|&&y| &y == x).count();
                 x * count as i32
             })
             .sum::<i32>()
-            .to_string()
+            .into())
     }
 }
 
@@ -48,7 +52,7 @@ mod tests {
             3   9
             3   3";
         let result = Day01.part1(input);
-        assert_eq!(result, 11.to_string());
+        assert_eq!(result, Ok(11.into()));
     }
 
     #[test]
@@ -60,6 +64,15 @@ mod tests {
             3   9
             3   3";
         let result = Day01.part2(input);
-        assert_eq!(result, "31".to_string());
+        assert_eq!(result, Ok(31.into()));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let result = Day01.part1("3   4\n4   x");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 5: expected integer, found \"x\""
+        );
     }
 }
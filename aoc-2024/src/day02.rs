@@ -1,6 +1,7 @@
-use crate::{utils, Solution};
+use aoc_core::{register, utils, Answer, Result, Solution};
 
 pub struct Day02;
+register!(2024, 2, Day02);
 
 fn eval_safety(levels: &[i32]) -> bool {
     let mut rising: Option<bool> = None;
@@ -39,22 +40,20 @@ fn eval_safety_p2(levels: &[i32]) -> bool {
 }
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> String {
-        input
-            .lines()
-            .map(utils::parse_numbers_from_string)
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(utils::parse_lines(input, utils::parse_numbers_from_string)?
+            .iter()
             .filter(|numbers| eval_safety(numbers))
             .count()
-            .to_string()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        input
-            .lines()
-            .map(utils::parse_numbers_from_string)
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(utils::parse_lines(input, utils::parse_numbers_from_string)?
+            .iter()
             .filter(|numbers| eval_safety_p2(numbers))
             .count()
-            .to_string()
+            .into())
     }
 }
 
@@ -70,58 +69,46 @@ mod tests {
 
     #[test]
     fn test_eval_safety_p1() {
-        assert_eq!(
-            eval_safety(&utils::parse_numbers_from_string(LEVELS_1)),
-            true
-        );
-        assert_eq!(
-            eval_safety(&utils::parse_numbers_from_string(LEVELS_2)),
-            false
-        );
-        assert_eq!(
-            eval_safety(&utils::parse_numbers_from_string(LEVELS_3)),
-            false
-        );
-        assert_eq!(
-            eval_safety(&utils::parse_numbers_from_string(LEVELS_4)),
-            false
-        );
-        assert_eq!(
-            eval_safety(&utils::parse_numbers_from_string(LEVELS_5)),
-            false
-        );
-        assert_eq!(
-            eval_safety(&utils::parse_numbers_from_string(LEVELS_6)),
-            true
-        );
+        assert!(eval_safety(
+            &utils::parse_numbers_from_string(LEVELS_1).unwrap()
+        ));
+        assert!(!eval_safety(
+            &utils::parse_numbers_from_string(LEVELS_2).unwrap()
+        ));
+        assert!(!eval_safety(
+            &utils::parse_numbers_from_string(LEVELS_3).unwrap()
+        ));
+        assert!(!eval_safety(
+            &utils::parse_numbers_from_string(LEVELS_4).unwrap()
+        ));
+        assert!(!eval_safety(
+            &utils::parse_numbers_from_string(LEVELS_5).unwrap()
+        ));
+        assert!(eval_safety(
+            &utils::parse_numbers_from_string(LEVELS_6).unwrap()
+        ));
     }
 
     #[test]
     fn test_eval_safety_p2() {
-        assert_eq!(
-            eval_safety_p2(&utils::parse_numbers_from_string(LEVELS_1)),
-            true
-        );
-        assert_eq!(
-            eval_safety_p2(&utils::parse_numbers_from_string(LEVELS_2)),
-            false
-        );
-        assert_eq!(
-            eval_safety_p2(&utils::parse_numbers_from_string(LEVELS_3)),
-            false
-        );
-        assert_eq!(
-            eval_safety_p2(&utils::parse_numbers_from_string(LEVELS_4)),
-            true
-        );
-        assert_eq!(
-            eval_safety_p2(&utils::parse_numbers_from_string(LEVELS_5)),
-            true
-        );
-        assert_eq!(
-            eval_safety_p2(&utils::parse_numbers_from_string(LEVELS_6)),
-            true
-        );
+        assert!(eval_safety_p2(
+            &utils::parse_numbers_from_string(LEVELS_1).unwrap()
+        ));
+        assert!(!eval_safety_p2(
+            &utils::parse_numbers_from_string(LEVELS_2).unwrap()
+        ));
+        assert!(!eval_safety_p2(
+            &utils::parse_numbers_from_string(LEVELS_3).unwrap()
+        ));
+        assert!(eval_safety_p2(
+            &utils::parse_numbers_from_string(LEVELS_4).unwrap()
+        ));
+        assert!(eval_safety_p2(
+            &utils::parse_numbers_from_string(LEVELS_5).unwrap()
+        ));
+        assert!(eval_safety_p2(
+            &utils::parse_numbers_from_string(LEVELS_6).unwrap()
+        ));
     }
 
     #[test]
@@ -133,7 +120,7 @@ mod tests {
                            8 6 4 4 1
                            1 3 6 7 9";
         let result = Day02.part1(input);
-        assert_eq!(result, 2.to_string());
+        assert_eq!(result, Ok(2.into()));
     }
 
     #[test]
@@ -145,6 +132,6 @@ mod tests {
                            8 6 4 4 1
                            1 3 6 7 9";
         let result = Day02.part2(input);
-        assert_eq!(result, 4.to_string());
+        assert_eq!(result, Ok(4.into()));
     }
 }
@@ -1,42 +1,43 @@
-use crate::Solution;
+use aoc_core::{register, utils, Answer, Result, Solution};
 use regex::Regex;
 
 pub struct Day03;
+register!(2024, 3, Day03);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> String {
+    fn part1(&self, input: &str) -> Result<Answer> {
         let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
-        let sum = re.captures_iter(input).fold(0, |sum, cap| {
-            let m1 = cap[1].parse::<i32>().unwrap();
-            let m2 = cap[2].parse::<i32>().unwrap();
-            sum + m1 * m2
-        });
-        sum.to_string()
+        let sum = re.captures_iter(input).try_fold(0, |sum, cap| {
+            let m1: i32 = utils::parse_token(input, &cap[1], "integer")?;
+            let m2: i32 = utils::parse_token(input, &cap[2], "integer")?;
+            Result::Ok(sum + m1 * m2)
+        })?;
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> String {
+    fn part2(&self, input: &str) -> Result<Answer> {
         let re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
         let mut allowed = true;
-        let sum = re.captures_iter(input).fold(0, |sum, cap| {
+        let sum = re.captures_iter(input).try_fold(0, |sum, cap| {
             let matched = cap.get(0).unwrap().as_str();
             match matched {
                 "do()" => {
                     allowed = true;
-                    sum
+                    Ok(sum)
                 }
                 "don't()" => {
                     allowed = false;
-                    sum
+                    Ok(sum)
                 }
                 _ if allowed => {
-                    let m1 = cap[1].parse::<i32>().unwrap();
-                    let m2 = cap[2].parse::<i32>().unwrap();
-                    sum + m1 * m2
+                    let m1: i32 = utils::parse_token(input, &cap[1], "integer")?;
+                    let m2: i32 = utils::parse_token(input, &cap[2], "integer")?;
+                    Ok(sum + m1 * m2)
                 }
-                _ => sum,
+                _ => Ok(sum),
             }
-        });
-        sum.to_string()
+        })?;
+        Ok(sum.into())
     }
 }
 
@@ -48,7 +49,7 @@ mod tests {
     fn test_part1() {
         let input: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
         let result = Day03.part1(input);
-        assert_eq!(result, 161.to_string());
+        assert_eq!(result, Ok(161.into()));
     }
 
     #[test]
@@ -56,6 +57,6 @@ mod tests {
         let input: &str =
             "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
         let result = Day03.part2(input);
-        assert_eq!(result, 48.to_string());
+        assert_eq!(result, Ok(48.into()));
     }
 }
@@ -1,75 +1,58 @@
-use crate::Solution;
+use aoc_core::utils::{Coord, Direction8, Grid};
+use aoc_core::{register, Answer, Result, Solution};
 
 pub struct Day04;
+register!(2024, 4, Day04);
 
-const DIRECTIONS: &[(i32, i32)] = &[
-    (0, -1),
-    (0, 1),
-    (-1, 0),
-    (1, 0),
-    (1, 1),
-    (1, -1),
-    (-1, 1),
-    (-1, -1),
-];
-
-fn get_element(map: &Vec<Vec<char>>, x: i32, y: i32) -> char {
-    if x < 0 || x >= map[0].len() as i32 || y < 0 || y >= map.len() as i32 {
-        return '.';
-    }
-    map[y as usize][x as usize]
+fn get_element(map: &Grid<char>, pos: Coord) -> char {
+    map.get(pos).copied().unwrap_or('.')
 }
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> String {
+    fn part1(&self, input: &str) -> Result<Answer> {
         const PATTERN: &str = "XMAS";
         let mut res = 0;
-        let map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-        for (y, row) in map.iter().enumerate() {
-            for (x, c) in row.iter().enumerate() {
-                if *c == PATTERN[0..1].chars().next().unwrap() {
-                    for dir in DIRECTIONS {
-                        for i in 1..PATTERN.len() {
-                            let new_x = x as i32 + dir.0 * i as i32;
-                            let new_y = y as i32 + dir.1 * i as i32;
-                            let el = get_element(&map, new_x, new_y);
-                            if el != PATTERN[i..i + 1].chars().next().unwrap() {
-                                break;
-                            }
-                            if i == PATTERN.len() - 1 {
-                                res += 1;
-                            }
+        let map = Grid::parse(input, Ok)?;
+        for (pos, c) in map.iter() {
+            if *c == PATTERN[0..1].chars().next().unwrap() {
+                for dir in Direction8::ALL {
+                    for i in 1..PATTERN.len() {
+                        let el = get_element(&map, pos + dir.to_coord() * i as i32);
+                        if el != PATTERN[i..i + 1].chars().next().unwrap() {
+                            break;
+                        }
+                        if i == PATTERN.len() - 1 {
+                            res += 1;
                         }
                     }
                 }
             }
         }
-        res.to_string()
+        Ok(res.into())
     }
 
-    fn part2(&self, input: &str) -> String {
+    fn part2(&self, input: &str) -> Result<Answer> {
         let mut res = 0;
-        let map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-        for (y, row) in map.iter().enumerate() {
-            for (x, c) in row.iter().enumerate() {
-                if *c == 'A' {
-                    let c1_d1 = get_element(&map, x as i32 - 1, y as i32 - 1);
-                    let c2_d1 = get_element(&map, x as i32 + 1, y as i32 + 1);
-                    let c1_d2 = get_element(&map, x as i32 - 1, y as i32 + 1);
-                    let c2_d2 = get_element(&map, x as i32 + 1, y as i32 - 1);
-                    let diag1 = &[c1_d1, c2_d1];
-                    let diag2 = &[c1_d2, c2_d2];
-                    if diag1.contains(&'M')
-                        && diag1.contains(&'S')
-                        && diag2.contains(&'M')
-                        && diag2.contains(&'S')
-                    {
-                        res += 1;
-                    }
+        let map = Grid::parse(input, Ok)?;
+        for (pos, c) in map.iter() {
+            if *c == 'A' {
+                let corner = |dir: Direction8| get_element(&map, pos + dir.to_coord());
+                let c1_d1 = corner(Direction8::NW);
+                let c2_d1 = corner(Direction8::SE);
+                let c1_d2 = corner(Direction8::SW);
+                let c2_d2 = corner(Direction8::NE);
+                let diag1 = &[c1_d1, c2_d1];
+                let diag2 = &[c1_d2, c2_d2];
+                if diag1.contains(&'M')
+                    && diag1.contains(&'S')
+                    && diag2.contains(&'M')
+                    && diag2.contains(&'S')
+                {
+                    res += 1;
                 }
             }
         }
-        res.to_string()
+        Ok(res.into())
     }
 }
 
@@ -90,7 +73,7 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX";
         let result = Day04.part1(input);
-        assert_eq!(result, 18.to_string());
+        assert_eq!(result, Ok(18.into()));
     }
 
     #[test]
@@ -106,6 +89,6 @@ S.S.S.S.S.
 M.M.M.M.M.
 ..........";
         let result = Day04.part2(input);
-        assert_eq!(result, 9.to_string());
+        assert_eq!(result, Ok(9.into()));
     }
 }
@@ -1,6 +1,7 @@
-use crate::{utils, Solution};
+use aoc_core::{register, utils, Answer, Result, Solution};
 
 pub struct Day05;
+register!(2024, 5, Day05);
 
 type Rules = Vec<(i32, i32)>;
 type Update = Vec<i32>;
@@ -45,47 +46,43 @@ fn sort(rules: &Rules, update: &Update) -> Update {
     res
 }
 
-fn parse_rules(input: &str) -> Rules {
-    input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split('|');
-            (
-                parts.next().unwrap().parse().unwrap(),
-                parts.next().unwrap().parse().unwrap(),
-            )
-        })
-        .collect()
+fn parse_rules(input: &str) -> Result<Rules> {
+    utils::parse_lines(input, utils::integer_tuple)
+}
+
+fn parse_input(input: &str) -> Result<(Rules, Vec<Update>)> {
+    let mut blocks = utils::blocks(input);
+    let rules_block = blocks.next().unwrap_or_default();
+    let updates_block = blocks.next().unwrap_or_default();
+    let rules = parse_rules(rules_block).map_err(|err| err.within(input, rules_block))?;
+    // Every update needs a middle page, so its pages are parsed strictly.
+    let updates = utils::parse_lines(updates_block, utils::parse_numbers_from_string_comma)
+        .map_err(|err| err.within(input, updates_block))?;
+    Ok((rules, updates))
 }
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> String {
-        let mut parts = input.split("\n\n");
-        let rules = parse_rules(parts.next().unwrap());
-        let updates = parts.next().unwrap().lines();
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let (rules, updates) = parse_input(input)?;
         let mut res = 0;
         for update in updates {
-            let update = utils::parse_numbers_from_string_comma(update);
             if is_sorted(&rules, &update) {
                 res += update[update.len() / 2];
             }
         }
-        res.to_string()
+        Ok(res.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let mut parts = input.split("\n\n");
-        let rules = parse_rules(parts.next().unwrap());
-        let updates = parts.next().unwrap().lines();
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let (rules, updates) = parse_input(input)?;
         let mut res = 0;
         for update in updates {
-            let update = utils::parse_numbers_from_string_comma(update);
             if !is_sorted(&rules, &update) {
                 let sorted = sort(&rules, &update);
                 res += sorted[sorted.len() / 2];
             }
         }
-        res.to_string()
+        Ok(res.into())
     }
 }
 
@@ -116,53 +113,36 @@ mod tests {
 75|13
 53|13",
         )
+        .unwrap()
     }
 
     #[test]
     fn test_is_sorted() {
         let test_rules = get_test_rules();
-        assert_eq!(
-            is_sorted(
-                &test_rules,
-                &utils::parse_numbers_from_string_comma("75,47,61,53,29")
-            ),
-            true
-        );
-        assert_eq!(
-            is_sorted(
-                &test_rules,
-                &utils::parse_numbers_from_string_comma("97,61,53,29,13")
-            ),
-            true
-        );
-        assert_eq!(
-            is_sorted(
-                &test_rules,
-                &utils::parse_numbers_from_string_comma("75,29,13")
-            ),
-            true
-        );
-        assert_eq!(
-            is_sorted(
-                &test_rules,
-                &utils::parse_numbers_from_string_comma("75,97,47,61,53")
-            ),
-            false
-        );
-        assert_eq!(
-            is_sorted(
-                &test_rules,
-                &utils::parse_numbers_from_string_comma("61,13,29")
-            ),
-            false
-        );
-        assert_eq!(
-            is_sorted(
-                &test_rules,
-                &utils::parse_numbers_from_string_comma("97,13,75,29,47")
-            ),
-            false
-        );
+        assert!(is_sorted(
+            &test_rules,
+            &utils::parse_numbers_from_string_comma("75,47,61,53,29").unwrap()
+        ));
+        assert!(is_sorted(
+            &test_rules,
+            &utils::parse_numbers_from_string_comma("97,61,53,29,13").unwrap()
+        ));
+        assert!(is_sorted(
+            &test_rules,
+            &utils::parse_numbers_from_string_comma("75,29,13").unwrap()
+        ));
+        assert!(!is_sorted(
+            &test_rules,
+            &utils::parse_numbers_from_string_comma("75,97,47,61,53").unwrap()
+        ));
+        assert!(!is_sorted(
+            &test_rules,
+            &utils::parse_numbers_from_string_comma("61,13,29").unwrap()
+        ));
+        assert!(!is_sorted(
+            &test_rules,
+            &utils::parse_numbers_from_string_comma("97,13,75,29,47").unwrap()
+        ));
     }
 
     #[test]
@@ -171,21 +151,21 @@ mod tests {
         assert_eq!(
             sort(
                 &test_rules,
-                &utils::parse_numbers_from_string_comma("75,97,47,61,53")
+                &utils::parse_numbers_from_string_comma("75,97,47,61,53").unwrap()
             ),
             vec![97, 75, 47, 61, 53]
         );
         assert_eq!(
             sort(
                 &test_rules,
-                &utils::parse_numbers_from_string_comma("61,13,29")
+                &utils::parse_numbers_from_string_comma("61,13,29").unwrap()
             ),
             vec![61, 29, 13]
         );
         assert_eq!(
             sort(
                 &test_rules,
-                &utils::parse_numbers_from_string_comma("97,13,75,29,47")
+                &utils::parse_numbers_from_string_comma("97,13,75,29,47").unwrap()
             ),
             vec![97, 75, 47, 29, 13]
         );
@@ -222,13 +202,23 @@ mod tests {
 61,13,29
 97,13,75,29,47";
         let result = Day05.part1(input);
-        assert_eq!(result, 143.to_string());
+        assert_eq!(result, Ok(143.into()));
     }
 
     #[test]
     fn test_part2() {
         let input: &str = "";
         let result = Day05.part2(input);
-        assert_eq!(result, 0.to_string());
+        assert_eq!(result, Ok(0.into()));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Day05.part1("47|53\n\n75,47\nabc").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected integer, found \"abc\""
+        );
+        assert!(Day05.part2("47|53\n\n75,,47").is_err());
     }
 }
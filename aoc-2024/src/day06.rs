@@ -1,20 +1,10 @@
-use crate::utils::{Coord, Direction};
+use aoc_core::utils::{Coord, Direction, Grid};
 use std::collections::HashSet;
 
-use crate::Solution;
+use aoc_core::{register, Answer, Error, Result, Solution};
 
 pub struct Day06;
-
-impl Direction {
-    fn turn_right(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Down => Direction::Left,
-            Direction::Right => Direction::Down,
-            Direction::Left => Direction::Up,
-        }
-    }
-}
+register!(2024, 6, Day06);
 
 #[derive(Copy, Clone, Debug)]
 enum Terrain {
@@ -22,7 +12,7 @@ enum Terrain {
     Obstacle,
 }
 
-type Map = Vec<Vec<Terrain>>;
+type Map = Grid<Terrain>;
 
 fn do_walk(map: &Map, start: Coord, dir: Direction) -> HashSet<Coord> {
     let mut visited: HashSet<Coord> = HashSet::new();
@@ -32,7 +22,7 @@ fn do_walk(map: &Map, start: Coord, dir: Direction) -> HashSet<Coord> {
     loop {
         // look ahead
         let ahead = pos + dir.to_coord();
-        match get_element(&map, ahead) {
+        match map.get(ahead) {
             Some(Terrain::Empty) => {
                 // move forward
                 pos = ahead;
@@ -64,11 +54,7 @@ fn is_loop(map: &Map, start: Coord, dir: Direction, extra_obstacle: Coord) -> bo
             continue;
         }
 
-        if get_element(&map, ahead).is_none() {
-            return false;
-        }
-
-        match get_element(&map, ahead) {
+        match map.get(ahead) {
             Some(Terrain::Empty) => {
                 pos = ahead;
             }
@@ -80,53 +66,34 @@ fn is_loop(map: &Map, start: Coord, dir: Direction, extra_obstacle: Coord) -> bo
     }
 }
 
-fn get_element(map: &Map, coord: Coord) -> Option<Terrain> {
-    let x = coord.x;
-    let y = coord.y;
-    if x < 0 || x >= map[0].len() as i32 || y < 0 || y >= map.len() as i32 {
-        return None;
-    }
-    Some(map[y as usize][x as usize])
-}
-
-fn read_input(input: &str) -> (Map, Coord) {
-    let mut guard_pos = Coord { x: 0, y: 0 };
-    let mut map = Map::new();
-    for (y, row) in input.lines().enumerate() {
-        let mut map_row = Vec::new();
-        for (x, c) in row.chars().enumerate() {
-            let terrain = match c {
-                '.' => Terrain::Empty,
-                '^' => {
-                    guard_pos.x = x as i32;
-                    guard_pos.y = y as i32;
-                    Terrain::Empty
-                }
-                _ => Terrain::Obstacle,
-            };
-            map_row.push(terrain);
-        }
-        map.push(map_row);
-    }
-    (map, guard_pos)
+fn read_input(input: &str) -> Result<(Map, Coord)> {
+    let chars = Grid::parse(input, Ok)?;
+    let guard_pos = chars
+        .find(&'^')
+        .ok_or_else(|| Error::parse("a guard `^`", "none"))?;
+    let map = chars.map(|&c| match c {
+        '.' | '^' => Terrain::Empty,
+        _ => Terrain::Obstacle,
+    });
+    Ok((map, guard_pos))
 }
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> String {
-        let (map, guard_pos) = read_input(input);
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let (map, guard_pos) = read_input(input)?;
         let dir = Direction::Up;
         let walk = do_walk(&map, guard_pos, dir);
-        walk.len().to_string() // 5153
+        Ok(walk.len().into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let (map, guard_pos) = read_input(input);
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let (map, guard_pos) = read_input(input)?;
         let dir = Direction::Up;
         let walk = do_walk(&map, guard_pos, dir);
 
         let mut res = 0;
         for pos in walk {
-            if pos == guard_pos || matches!(get_element(&map, pos), Some(Terrain::Obstacle)) {
+            if pos == guard_pos || matches!(map[pos], Terrain::Obstacle) {
                 continue;
             }
             // Check if placing an obstacle here creates a loop
@@ -135,7 +102,7 @@ impl Solution for Day06 {
             }
         }
 
-        res.to_string()
+        Ok(res.into())
     }
 }
 
@@ -156,7 +123,7 @@ mod tests {
 #.........
 ......#...";
         let result = Day06.part1(input);
-        assert_eq!(result, 41.to_string());
+        assert_eq!(result, Ok(41.into()));
     }
 
     #[test]
@@ -172,6 +139,6 @@ mod tests {
 #.........
 ......#...";
         let result = Day06.part2(input);
-        assert_eq!(result, 6.to_string());
+        assert_eq!(result, Ok(6.into()));
     }
 }
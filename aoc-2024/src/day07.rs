@@ -1,107 +1,116 @@
-use crate::Solution;
+use std::hint::black_box;
+
+use aoc_core::utils::memo::Memo;
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
 
 pub struct Day07;
+register!(2024, 7, Day07);
 
 #[derive(Debug)]
 struct Equation {
     test_value: i64,
     numbers: Vec<i64>,
+    /// No operator makes a value smaller then, so a value past the test value
+    /// is a dead end.
+    all_positive: bool,
 }
 
-fn concat(a: i64, b: i64) -> i64 {
-    let b_str = b.to_string();
-    format!("{}{}", a, b_str).parse().unwrap()
+#[derive(Copy, Clone, Debug)]
+enum Operator {
+    Add,
+    Mul,
+    Concat,
 }
 
-fn evaluate(numbers: &[i64], operators: &[char]) -> i64 {
-    let mut result = numbers[0];
-    for i in 0..operators.len() {
-        match operators[i] {
-            '+' => result += numbers[i + 1],
-            '*' => result *= numbers[i + 1],
-            '|' => result = concat(result, numbers[i + 1]),
-            _ => panic!("Invalid operator"),
+impl Operator {
+    /// `None` if the result doesn't fit.
+    fn apply(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Mul => a.checked_mul(b),
+            Operator::Concat => format!("{}{}", a, b).parse().ok(),
         }
     }
-    result
 }
 
-fn try_all_operators(equation: &Equation) -> bool {
-    let op_count = equation.numbers.len() - 1;
-    let combinations = 2_i32.pow(op_count as u32); // 2^n combinations for n operator positions
-
-    for i in 0..combinations {
-        let mut operators = Vec::new();
-        for j in 0..op_count {
-            // Use bits of i to determine operator: 0 for '+', 1 for '*'
-            operators.push(if (i & (1 << j)) == 0 { '+' } else { '*' });
-        }
-
-        if evaluate(&equation.numbers, &operators) == equation.test_value {
-            return true;
-        }
+impl Equation {
+    /// Whether putting some of `operators` between the numbers, evaluated
+    /// left to right, gives the test value.
+    fn solvable(&self, operators: &[Operator]) -> bool {
+        self.reaches(operators, 1, self.numbers[0], &mut Memo::new())
     }
-    false
-}
-
-fn try_all_operators2(equation: &Equation) -> bool {
-    let op_count = equation.numbers.len() - 1;
-    let combinations = 3_i32.pow(op_count as u32); // 3^n combinations for n operator positions
-
-    for i in 0..combinations {
-        let mut operators = Vec::new();
-        for j in 0..op_count {
-            // Use base-3 digits to determine operator: 0 for '+', 1 for '*', 2 for '||'
-            let op = match (i / 3_i32.pow(j as u32)) % 3 {
-                0 => '+',
-                1 => '*',
-                2 => '|',
-                _ => unreachable!(),
-            };
-            operators.push(op);
-        }
 
-        if evaluate(&equation.numbers, &operators) == equation.test_value {
-            return true;
+    /// Whether the rest of the numbers from `idx` on can turn `value` into the
+    /// test value. Different operators often lead to the same value, which
+    /// is only tried once.
+    fn reaches(
+        &self,
+        operators: &[Operator],
+        idx: usize,
+        value: i64,
+        memo: &mut Memo<(usize, i64), bool>,
+    ) -> bool {
+        let Some(&next) = self.numbers.get(idx) else {
+            return value == self.test_value;
+        };
+        if self.all_positive && value > self.test_value {
+            return false;
         }
+        memo.call((idx, value), |memo| {
+            operators.iter().any(|op| {
+                op.apply(value, next)
+                    .is_some_and(|value| self.reaches(operators, idx + 1, value, memo))
+            })
+        })
     }
-    false
 }
 
-fn parse_equation(line: &str) -> Equation {
-    let parts: Vec<&str> = line.split(": ").collect();
-    let test_value = parts[0].parse().unwrap();
-    let numbers: Vec<i64> = parts[1]
+fn parse_equation(line: &str) -> Result<Equation> {
+    let (test_value, numbers) = line
+        .split_once(": ")
+        .ok_or_else(|| Error::parse("an equation `value: numbers`", line))?;
+    let test_value = utils::parse_token(line, test_value, "integer")?;
+    let numbers: Vec<i64> = numbers
         .split_whitespace()
-        .map(|n| n.parse().unwrap())
-        .collect();
-    Equation {
+        .map(|n| utils::parse_token(line, n, "integer"))
+        .collect::<Result<_>>()?;
+    if numbers.is_empty() {
+        return Err(Error::parse("at least one number", line));
+    }
+    Ok(Equation {
         test_value,
+        all_positive: numbers.iter().all(|&n| n > 0),
         numbers,
-    }
+    })
 }
 
 impl Solution for Day07 {
-    fn part1(&self, input: &str) -> String {
-        let equations: Vec<Equation> = input.lines().map(parse_equation).collect();
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let equations = utils::parse_lines(input, parse_equation)?;
 
-        equations
+        Ok(equations
             .iter()
-            .filter(|eq| try_all_operators(eq))
+            .filter(|eq| eq.solvable(&[Operator::Add, Operator::Mul]))
             .map(|eq| eq.test_value)
             .sum::<i64>()
-            .to_string()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let equations: Vec<Equation> = input.lines().map(parse_equation).collect();
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let equations = utils::parse_lines(input, parse_equation)?;
 
-        equations
+        Ok(equations
             .iter()
-            .filter(|eq| try_all_operators2(eq))
+            .filter(|eq| eq.solvable(&[Operator::Add, Operator::Mul, Operator::Concat]))
             .map(|eq| eq.test_value)
             .sum::<i64>()
-            .to_string()
+            .into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(utils::parse_lines(input, parse_equation).map(|equations| {
+            black_box(equations);
+        }))
     }
 }
 
@@ -121,7 +130,7 @@ mod tests {
 21037: 9 7 18 13
 292: 11 6 16 20";
         let result = Day07.part1(input);
-        assert_eq!(result, 3749.to_string());
+        assert_eq!(result, Ok(3749.into()));
     }
 
     #[test]
@@ -136,6 +145,18 @@ mod tests {
 21037: 9 7 18 13
 292: 11 6 16 20";
         let result = Day07.part2(input);
-        assert_eq!(result, 11387.to_string());
+        assert_eq!(result, Ok(11387.into()));
+    }
+
+    #[test]
+    fn test_solvable() {
+        let all = [Operator::Add, Operator::Mul, Operator::Concat];
+        let equation = |line| parse_equation(line).unwrap();
+        // Multiplying by 0 goes back below the test value.
+        assert!(equation("3: 5 7 0 3").solvable(&all[..2]));
+        assert!(equation("156: 15 6").solvable(&all));
+        assert!(!equation("156: 15 6").solvable(&all[..2]));
+        // Concatenating these overflows, which is no way to the test value.
+        assert!(!equation("1: 9223372036854775807 9").solvable(&all));
     }
 }
@@ -1,8 +1,9 @@
 use std::collections::HashSet;
 
-use crate::Solution;
+use aoc_core::{register, Answer, Result, Solution};
 
 pub struct Day08;
+register!(2024, 8, Day08);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 struct Antenna {
@@ -56,7 +57,7 @@ fn read_input(input: &str) -> City {
 }
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> String {
+    fn part1(&self, input: &str) -> Result<Answer> {
         let mut city = read_input(input);
         let antennas = city.antennas.clone();
         let mut pairs_evaluated: HashSet<(Antenna, Antenna)> = HashSet::new();
@@ -71,10 +72,10 @@ impl Solution for Day08 {
                 city.push_antinode(a2.x - dx, a2.y - dy);
             }
         }
-        city.antinodes.len().to_string() // 398
+        Ok(city.antinodes.len().into())
     }
 
-    fn part2(&self, input: &str) -> String {
+    fn part2(&self, input: &str) -> Result<Answer> {
         let mut city = read_input(input);
         let antennas = city.antennas.clone();
         let mut pairs_evaluated: HashSet<(Antenna, Antenna)> = HashSet::new();
@@ -93,7 +94,7 @@ impl Solution for Day08 {
                 }
             }
         }
-        city.antinodes.len().to_string() // 1333
+        Ok(city.antinodes.len().into())
     }
 }
 
@@ -116,7 +117,7 @@ mod tests {
 ............
 ............";
         let result = Day08.part1(input);
-        assert_eq!(result, 14.to_string());
+        assert_eq!(result, Ok(14.into()));
     }
 
     #[test]
@@ -134,6 +135,6 @@ mod tests {
 ............
 ............";
         let result = Day08.part2(input);
-        assert_eq!(result, 34.to_string());
+        assert_eq!(result, Ok(34.into()));
     }
 }
@@ -1,6 +1,9 @@
-use crate::Solution;
+use std::hint::black_box;
+
+use aoc_core::{register, Answer, Error, Result, Solution};
 
 pub struct Day09;
+register!(2024, 9, Day09);
 
 #[derive(Debug, Clone)]
 struct Block {
@@ -12,12 +15,15 @@ struct Disk {
 }
 
 impl Disk {
-    fn from(s: &str) -> Self {
+    fn parse(s: &str) -> Result<Self> {
+        let s = s.trim_end();
         let mut blocks = Vec::new();
         let mut is_file = true;
         let mut file_id = 0;
-        for c in s.chars() {
-            let Some(digit) = c.to_digit(10) else { break };
+        for (idx, c) in s.char_indices() {
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| Error::parse_at(s, &s[idx..idx + c.len_utf8()], "digit"))?;
             if is_file {
                 for _ in 0..digit {
                     blocks.push(Block {
@@ -32,14 +38,11 @@ impl Disk {
             }
             is_file = !is_file;
         }
-        Self { blocks }
+        Ok(Self { blocks })
     }
 
-    fn find_first_empty(&self) -> usize {
-        self.blocks
-            .iter()
-            .position(|b| b.file_id.is_none())
-            .unwrap()
+    fn find_first_empty(&self) -> Option<usize> {
+        self.blocks.iter().position(|b| b.file_id.is_none())
     }
 
     fn defragment(&mut self) {
@@ -47,16 +50,18 @@ impl Disk {
         while i > 0 {
             i -= 1;
             if self.blocks[i].file_id.is_some() {
-                let j = self.find_first_empty();
-                if j > i {
+                let Some(j) = self.find_first_empty().filter(|&j| j < i) else {
                     break;
-                }
+                };
                 self.blocks.swap(i, j);
             }
         }
     }
 
     fn defragment2(&mut self) {
+        if self.blocks.is_empty() {
+            return;
+        }
         let mut pos = self.blocks.len() - 1;
         let mut file_id = self.blocks[pos].file_id.unwrap_or(0);
 
@@ -89,6 +94,7 @@ impl Disk {
                     gap_len += 1;
                     if gap_len >= file_size {
                         best_gap = Some(gap_pos - gap_len + 1);
+                        break;
                     }
                 } else {
                     gap_len = 0;
@@ -104,6 +110,9 @@ impl Disk {
                 }
             }
 
+            if file_id == 0 {
+                break;
+            }
             file_id -= 1;
             pos = self.blocks.len() - 1;
         }
@@ -119,16 +128,22 @@ impl Disk {
 }
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> String {
-        let mut disk = Disk::from(input);
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let mut disk = Disk::parse(input)?;
         disk.defragment();
-        disk.checksum().to_string()
+        Ok(disk.checksum().into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let mut disk = Disk::from(input);
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let mut disk = Disk::parse(input)?;
         disk.defragment2();
-        disk.checksum().to_string()
+        Ok(disk.checksum().into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(Disk::parse(input).map(|disk| {
+            black_box(disk);
+        }))
     }
 }
 
@@ -140,13 +155,13 @@ mod tests {
     fn test_part1() {
         let input: &str = "2333133121414131402";
         let result = Day09.part1(input);
-        assert_eq!(result, 1928.to_string());
+        assert_eq!(result, Ok(1928.into()));
     }
 
     #[test]
     fn test_part2() {
         let input: &str = "2333133121414131402";
         let result = Day09.part2(input);
-        assert_eq!(result, 2858.to_string());
+        assert_eq!(result, Ok(2858.into()));
     }
 }
@@ -1,111 +1,79 @@
-use std::collections::HashSet;
+use std::hint::black_box;
 
-use crate::Solution;
+use aoc_core::utils::search::{self, Paths};
+use aoc_core::utils::{Coord, Grid};
+use aoc_core::{register, Answer, Error, Result, Solution};
 
 pub struct Day10;
+register!(2024, 10, Day10);
 
 type Height = usize;
 
 #[derive(Debug)]
 struct Map {
-    width: usize,
-    height: usize,
-    heights: Vec<Vec<Height>>,
+    heights: Grid<Height>,
 }
 impl Map {
-    fn from(input: &str) -> Self {
-        let mut heights: Vec<Vec<Height>> = Vec::new();
-        let mut width = 0;
-        let mut height = 0;
-        for line in input.lines() {
-            width = line.len();
-            height += 1;
-            let mut row = Vec::new();
-            for c in line.chars() {
-                let height = c.to_digit(10).unwrap() as Height;
-                row.push(height);
-            }
-            heights.push(row);
-        }
-
-        Self {
-            width,
-            height,
-            heights,
-        }
+    fn parse(input: &str) -> Result<Self> {
+        let heights = Grid::parse(input, |c| {
+            c.to_digit(10)
+                .map(|d| d as Height)
+                .ok_or_else(|| Error::parse("digit", c.to_string()))
+        })?;
+        Ok(Self { heights })
     }
 
-    fn for_low_points<F>(&self, f: F) -> usize
-    where
-        F: Fn(&Self, usize, usize) -> usize,
-    {
-        let mut res = 0;
-        for y in 0..self.height {
-            for x in 0..self.width {
-                if self.heights[y][x] == 0 {
-                    res += f(self, x, y);
-                }
-            }
-        }
-        res
+    fn trailheads(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.heights.find_all(&0)
     }
 
-    fn dfs_part1(&self, x: usize, y: usize, reached: &mut HashSet<(usize, usize)>) {
-        let curr = self.heights[y][x];
-        if curr == 9 {
-            reached.insert((x, y));
-            return;
-        }
-        self.get_adjacent_with_next(x, y)
-            .iter()
-            .for_each(|c| self.dfs_part1(c.0, c.1, reached));
+    /// Every trail climbs by one per step, so all paths found from the
+    /// trailheads are shortest ones.
+    fn trails(&self, trailheads: impl IntoIterator<Item = Coord>) -> Paths<Coord, usize> {
+        search::bfs(
+            trailheads,
+            |&pos| self.get_adjacent_with_next(pos),
+            |_| false,
+        )
     }
 
-    fn dfs_part2(&self, x: usize, y: usize) -> usize {
-        let curr = self.heights[y][x];
-        if curr == 9 {
-            return 1;
-        }
-        self.get_adjacent_with_next(x, y)
-            .iter()
-            .map(|c| self.dfs_part2(c.0, c.1))
-            .sum()
+    fn peaks<'a>(&'a self, trails: &'a Paths<Coord, usize>) -> impl Iterator<Item = Coord> + 'a {
+        self.heights
+            .find_all(&9)
+            .filter(|pos| trails.distance(pos).is_some())
     }
 
-    fn get_adjacent_with_next(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-        let curr_height = self.heights[y][x];
-        let mut result = Vec::new();
-        if x > 0 && self.heights[y][x - 1] == curr_height + 1 {
-            result.push((x - 1, y));
-        }
-        if x < self.width - 1 && self.heights[y][x + 1] == curr_height + 1 {
-            result.push((x + 1, y));
-        }
-        if y > 0 && self.heights[y - 1][x] == curr_height + 1 {
-            result.push((x, y - 1));
-        }
-        if y < self.height - 1 && self.heights[y + 1][x] == curr_height + 1 {
-            result.push((x, y + 1));
-        }
-        result
+    fn get_adjacent_with_next(&self, pos: Coord) -> impl Iterator<Item = Coord> + '_ {
+        let next_height = self.heights[pos] + 1;
+        pos.neighbors4()
+            .filter(move |&next| self.heights.get(next) == Some(&next_height))
     }
 }
 
 impl Solution for Day10 {
-    fn part1(&self, input: &str) -> String {
-        Map::from(input)
-            .for_low_points(|map, x, y| {
-                let mut reached = HashSet::new();
-                map.dfs_part1(x, y, &mut reached);
-                reached.len()
-            })
-            .to_string()
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let map = Map::parse(input)?;
+        let score: usize = map
+            .trailheads()
+            .map(|start| map.peaks(&map.trails([start])).count())
+            .sum();
+        Ok(score.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let map = Map::parse(input)?;
+        let trails = map.trails(map.trailheads());
+        let rating: u64 = map
+            .peaks(&trails)
+            .map(|peak| trails.path_count(&peak))
+            .sum();
+        Ok(rating.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        Map::from(input)
-            .for_low_points(|map, x, y| map.dfs_part2(x, y))
-            .to_string()
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(Map::parse(input).map(|map| {
+            black_box(map);
+        }))
     }
 }
 
@@ -123,7 +91,7 @@ mod tests {
 8444448
 9444449";
         let result = Day10.part1(input);
-        assert_eq!(result, 2.to_string());
+        assert_eq!(result, Ok(2.into()));
     }
 
     #[test]
@@ -137,7 +105,7 @@ mod tests {
 01329801
 10456732";
         let result = Day10.part1(input);
-        assert_eq!(result, 36.to_string());
+        assert_eq!(result, Ok(36.into()));
     }
 
     #[test]
@@ -151,6 +119,6 @@ mod tests {
 01329801
 10456732";
         let result = Day10.part2(input);
-        assert_eq!(result, 81.to_string());
+        assert_eq!(result, Ok(81.into()));
     }
 }
@@ -1,19 +1,25 @@
 use std::collections::HashMap;
 
-use crate::Solution;
+use aoc_core::utils;
+use aoc_core::{register, Answer, Result, Solution};
 
 pub struct Day11;
+register!(2024, 11, Day11);
 
 struct Pebbles {
     pebbles: HashMap<usize, usize>,
 }
 impl Pebbles {
-    fn from(input: &str) -> Self {
+    fn parse(input: &str) -> Result<Self> {
         let pebbles = input
             .split_whitespace()
-            .map(|s| (s.parse().unwrap(), 1))
-            .collect();
-        Self { pebbles }
+            .map(|s| Ok((utils::parse_token(input, s, "integer")?, 1)))
+            .collect::<Result<_>>()?;
+        Ok(Self { pebbles })
+    }
+
+    fn num_pebbles(&self) -> usize {
+        self.pebbles.values().sum()
     }
 
     fn make_steps(&mut self, steps: usize) -> &mut Self {
@@ -45,10 +51,6 @@ impl Pebbles {
             }
         }
     }
-
-    fn num_pebbles(&self) -> usize {
-        self.pebbles.values().sum()
-    }
 }
 
 fn has_even_digits(mut num: usize) -> bool {
@@ -61,18 +63,12 @@ fn has_even_digits(mut num: usize) -> bool {
 }
 
 impl Solution for Day11 {
-    fn part1(&self, input: &str) -> String {
-        Pebbles::from(input)
-            .make_steps(25)
-            .num_pebbles()
-            .to_string()
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(Pebbles::parse(input)?.make_steps(25).num_pebbles().into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        Pebbles::from(input)
-            .make_steps(75)
-            .num_pebbles()
-            .to_string()
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(Pebbles::parse(input)?.make_steps(75).num_pebbles().into())
     }
 }
 
@@ -84,6 +80,6 @@ mod tests {
     fn test_part1() {
         let input: &str = "125 17";
         let result = Day11.part1(input);
-        assert_eq!(result, 55312.to_string());
+        assert_eq!(result, Ok(55312.into()));
     }
 }
@@ -1,92 +1,147 @@
-use crate::Solution;
+use std::hint::black_box;
+
+use aoc_core::utils::{linalg, math};
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
 
 pub struct Day13;
+register!(2024, 13, Day13);
+
+type Vector = (i128, i128);
 
-type Vector = (f64, f64);
+const COST_A: i128 = 3;
+const COST_B: i128 = 1;
 
 struct Machine {
-    x: Vector,
-    y: Vector,
-    t: Vector,
+    a: Vector,
+    b: Vector,
+    prize: Vector,
 }
 
 impl Machine {
-    fn from(input: &str) -> Self {
+    fn parse(input: &str) -> Result<Self> {
         let mut lines = input.lines();
-        let parse_coords = |line: &str| {
-            let coords = line.split(": ").nth(1).unwrap();
-            let (x, y) = coords.split_once(", ").unwrap();
-            (
-                x.trim_start_matches("X")
-                    .trim_start_matches("+")
-                    .trim_start_matches("=")
-                    .parse::<f64>()
-                    .unwrap(),
-                y.trim_start_matches("Y")
-                    .trim_start_matches("+")
-                    .trim_start_matches("=")
-                    .parse::<f64>()
-                    .unwrap(),
-            )
+        let mut parse_coords = || -> Result<Vector> {
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::parse("three lines per machine", input))?;
+            utils::integer_tuple(line).map_err(|err| err.within(input, line))
         };
 
-        let x = parse_coords(lines.next().unwrap());
-        let y = parse_coords(lines.next().unwrap());
-        let t = parse_coords(lines.next().unwrap());
+        let a = parse_coords()?;
+        let b = parse_coords()?;
+        let prize = parse_coords()?;
 
-        Self { x, y, t }
+        Ok(Self { a, b, prize })
     }
 
     fn with_correction(&self) -> Self {
-        const CORRECTION: f64 = 10000000000000.0;
+        const CORRECTION: i128 = 10_000_000_000_000;
         Self {
-            t: (self.t.0 + CORRECTION, self.t.1 + CORRECTION),
+            prize: (self.prize.0 + CORRECTION, self.prize.1 + CORRECTION),
             ..(*self)
         }
     }
 
-    fn solve(&self) -> Option<i64> {
-        linsolve(self.x, self.y, self.t).map(|(a, b)| 3 * a as i64 + b as i64)
+    /// Fewest tokens that win the prize, if it can be won.
+    fn solve(&self) -> Option<i128> {
+        let (a, b) = self.presses()?;
+        Some(COST_A * a + COST_B * b)
+    }
+
+    /// Presses of A and B that move the claw onto the prize. There is at most
+    /// one way unless both buttons move along the same line.
+    fn presses(&self) -> Option<(i128, i128)> {
+        let matrix = [[self.a.0, self.b.0], [self.a.1, self.b.1]];
+        let Some(solution) = linalg::solve2(matrix, [self.prize.0, self.prize.1]) else {
+            return self.presses_on_line();
+        };
+        match linalg::integer_solution(&solution)?[..] {
+            [a, b] if a >= 0 && b >= 0 => Some((a, b)),
+            _ => None,
+        }
     }
-}
 
-fn float_eq(n1: f64, n2: f64) -> bool {
-    (n1 - n2).abs() < f64::EPSILON
+    /// The cheapest presses when both buttons move along the same line, which
+    /// the prize has to be on. Only one coordinate along it matters then.
+    fn presses_on_line(&self) -> Option<(i128, i128)> {
+        let cross = |u: Vector, v: Vector| u.0 * v.1 - u.1 * v.0;
+        if self.a == (0, 0) && self.b == (0, 0) {
+            return (self.prize == (0, 0)).then_some((0, 0));
+        }
+        if cross(self.a, self.prize) != 0 || cross(self.b, self.prize) != 0 {
+            return None;
+        }
+        if self.a.0 != 0 || self.b.0 != 0 {
+            cheapest_presses(self.a.0, self.b.0, self.prize.0)
+        } else {
+            cheapest_presses(self.a.1, self.b.1, self.prize.1)
+        }
+    }
 }
 
-fn linsolve(x: Vector, y: Vector, t: Vector) -> Option<Vector> {
-    let det = x.0 * y.1 - x.1 * y.0;
-    if float_eq(det, 0.0) {
+/// The cheapest `(x, y)` with `a * x + b * y = target` and neither negative.
+fn cheapest_presses(a: i128, b: i128, target: i128) -> Option<(i128, i128)> {
+    if a == 0 && b == 0 {
+        return (target == 0).then_some((0, 0));
+    }
+    let (g, x, y) = math::extended_gcd(a, b);
+    if target % g != 0 {
         return None;
     }
-
-    let a = (t.0 * y.1 - t.1 * y.0) / det;
-    let b = (x.0 * t.1 - x.1 * t.0) / det;
-
-    // test if a and b are integers
-    if !float_eq(a, a.round()) || !float_eq(b, b.round()) {
+    // All solutions are (x0 + k * dx, y0 + k * dy) for any k.
+    let (x0, y0) = (x * (target / g), y * (target / g));
+    let (dx, dy) = (b / g, -a / g);
+
+    // Each coordinate has to stay non-negative, which bounds k.
+    let (mut low, mut high) = (i128::MIN, i128::MAX);
+    for (base, step) in [(x0, dx), (y0, dy)] {
+        match step.signum() {
+            1 => low = low.max(-base.div_euclid(step)),
+            -1 => high = high.min(base.div_euclid(-step)),
+            _ if base < 0 => return None,
+            _ => {}
+        }
+    }
+    if low > high {
         return None;
     }
-    Some((a, b))
+    // The cost changes linearly with k, so one of the bounds is cheapest.
+    let slope = COST_A * dx + COST_B * dy;
+    let k = if slope > 0 || (slope == 0 && low > i128::MIN) {
+        low
+    } else {
+        high
+    };
+    Some((x0 + k * dx, y0 + k * dy))
+}
+
+fn parse_machines(input: &str) -> Result<Vec<Machine>> {
+    utils::parse_blocks(input, Machine::parse)
 }
 
 impl Solution for Day13 {
-    fn part1(&self, input: &str) -> String {
-        input
-            .split("\n\n")
-            .into_iter()
-            .map(|sys| Machine::from(sys).solve().unwrap_or(0))
-            .sum::<i64>()
-            .to_string()
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let machines = parse_machines(input)?;
+        Ok(machines
+            .iter()
+            .map(|machine| machine.solve().unwrap_or(0))
+            .sum::<i128>()
+            .into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let machines = parse_machines(input)?;
+        Ok(machines
+            .iter()
+            .map(|machine| machine.with_correction().solve().unwrap_or(0))
+            .sum::<i128>()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        input
-            .split("\n\n")
-            .into_iter()
-            .map(|sys| Machine::from(sys).with_correction().solve().unwrap_or(0))
-            .sum::<i64>()
-            .to_string()
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse_machines(input).map(|machines| {
+            black_box(machines);
+        }))
     }
 }
 
@@ -94,9 +149,7 @@ impl Solution for Day13 {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part1() {
-        let input: &str = "Button A: X+94, Y+34
+    const INPUT: &str = "Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
 
@@ -111,7 +164,45 @@ Prize: X=7870, Y=6450
 Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279";
+
+    #[test]
+    fn test_part1() {
+        let result = Day13.part1(INPUT);
+        assert_eq!(result, Ok(480.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = Day13.part2(INPUT);
+        assert_eq!(result, Ok(875318608908i64.into()));
+    }
+
+    #[test]
+    fn test_same_direction() {
+        // B is cheaper per step, A only when it goes far enough.
+        let input = "Button A: X+2, Y+2
+Button B: X+1, Y+1
+Prize: X=10, Y=10
+
+Button A: X+6, Y+6
+Button B: X+1, Y+1
+Prize: X=13, Y=13
+
+Button A: X+4, Y+2
+Button B: X+6, Y+3
+Prize: X=10, Y=5
+
+Button A: X+4, Y+2
+Button B: X+2, Y+1
+Prize: X=5, Y=3
+
+Button A: X+0, Y+3
+Button B: X+0, Y+5
+Prize: X=0, Y=7";
         let result = Day13.part1(input);
-        assert_eq!(result, 480.to_string());
+        assert_eq!(result, Ok((10 + 7 + 4).into()));
+        assert_eq!(cheapest_presses(6, 4, 2), None);
+        assert_eq!(cheapest_presses(6, -4, 2), Some((1, 1)));
+        assert_eq!(cheapest_presses(0, 5, 15), Some((0, 3)));
     }
 }
@@ -1,16 +1,40 @@
-use crate::{utils, Solution};
+use std::hint::black_box;
 
-pub struct Day14;
+use aoc_core::utils::{self, math};
+use aoc_core::{register, Answer, Error, Result, Solution};
 
-#[cfg(test)]
-const WIDTH: i32 = 11;
-#[cfg(not(test))]
-const WIDTH: i32 = 101;
+pub struct Day14 {
+    params: Params,
+}
+register!(2024, 14, Day14::PUZZLE);
 
-#[cfg(test)]
-const HEIGHT: i32 = 7;
-#[cfg(not(test))]
-const HEIGHT: i32 = 103;
+impl Day14 {
+    pub const PUZZLE: Day14 = Day14 {
+        params: Params {
+            width: 101,
+            height: 103,
+        },
+    };
+}
+
+/// Size of the area the robots move in, 11x7 in the example.
+#[derive(Copy, Clone, Debug)]
+struct Params {
+    width: i32,
+    height: i32,
+}
+
+impl Params {
+    fn with(mut self, given: &aoc_core::Params) -> Result<Self> {
+        given.check_keys(&["width", "height"])?;
+        given.apply("width", &mut self.width)?;
+        given.apply("height", &mut self.height)?;
+        if self.width <= 0 || self.height <= 0 {
+            return Err(Error::input("the width and height must be positive"));
+        }
+        Ok(self)
+    }
+}
 
 const N_STEPS: usize = 100;
 
@@ -23,55 +47,66 @@ struct Robot {
 }
 
 impl Robot {
-    fn from(input: &str) -> Self {
-        let mut parts = input.split_whitespace();
-        let p = parts.next().unwrap();
-        let v = parts.next().unwrap();
-        let mut p_parts = p.split(",");
-        let x = p_parts
-            .next()
-            .unwrap()
-            .trim_start_matches("p=")
-            .parse()
-            .unwrap();
-        let y = p_parts.next().unwrap().parse().unwrap();
-        let mut v_parts = v.split(",");
-        let vx = v_parts
-            .next()
-            .unwrap()
-            .trim_start_matches("v=")
-            .parse()
-            .unwrap();
-        let vy = v_parts.next().unwrap().parse().unwrap();
-        Self { x, y, vx, vy }
+    fn parse(line: &str) -> Result<Self> {
+        for (part, label) in line.split(' ').zip(["p=", "v="]) {
+            if !part.starts_with(label) {
+                return Err(Error::parse_at(line, part, format!("`{}X,Y`", label)));
+            }
+        }
+        let (x, y, vx, vy) = utils::integer_tuple(line)?;
+        Ok(Self { x, y, vx, vy })
     }
 }
 
+/// Variance of `positions`, times the square of their number.
+fn spread(positions: impl Iterator<Item = i32>) -> i64 {
+    let (count, sum, sum_squares) = positions.fold((0, 0, 0), |(count, sum, sum_squares), pos| {
+        let pos = pos as i64;
+        (count + 1, sum + pos, sum_squares + pos * pos)
+    });
+    count * sum_squares - sum * sum
+}
+
 struct Simulation {
     robots: Vec<Robot>,
+    width: i32,
+    height: i32,
 }
 
 impl Simulation {
-    fn new(robots: Vec<Robot>) -> Self {
-        Self { robots }
-    }
-    fn simulate(&mut self, n_steps: usize) -> &mut Self {
-        for _ in 0..n_steps {
-            self.step();
+    fn new(robots: Vec<Robot>, params: Params) -> Self {
+        Self {
+            robots,
+            width: params.width,
+            height: params.height,
         }
-        self
     }
 
-    fn step(&mut self) {
-        self.robots.iter_mut().for_each(|robot| {
-            robot.x = ((robot.x + robot.vx) % WIDTH + WIDTH) % WIDTH;
-            robot.y = ((robot.y + robot.vy) % HEIGHT + HEIGHT) % HEIGHT;
-        })
+    /// The robots after `steps` seconds. Each moves in a straight line that
+    /// wraps around, so it is placed there directly.
+    fn after(&self, steps: usize) -> Self {
+        let wrap = |pos: i32, velocity: i32, size: i32| {
+            (pos as i64 + velocity as i64 * steps as i64).rem_euclid(size as i64) as i32
+        };
+        let robots = self
+            .robots
+            .iter()
+            .map(|robot| Robot {
+                x: wrap(robot.x, robot.vx, self.width),
+                y: wrap(robot.y, robot.vy, self.height),
+                ..*robot
+            })
+            .collect();
+        Self {
+            robots,
+            width: self.width,
+            height: self.height,
+        }
     }
 
     fn eval_quadrants(&self) -> usize {
         let (mut q1, mut q2, mut q3, mut q4) = (0, 0, 0, 0);
-        let (borderline_x, borderline_y) = (WIDTH / 2, HEIGHT / 2);
+        let (borderline_x, borderline_y) = (self.width / 2, self.height / 2);
         for robot in &self.robots {
             // assign to quadrant but exclude the middle row and middle column
             if robot.x < borderline_x && robot.y < borderline_y {
@@ -87,57 +122,76 @@ impl Simulation {
         q1 * q2 * q3 * q4
     }
 
-    fn part2(&mut self) -> usize {
-        // p1 result: 225943500
-        // Use eval safety as "entropy" source.
-        let mut n_steps = 0;
-        loop {
-            n_steps += 1;
-            self.step();
-            let quad = self.eval_quadrants();
-            if quad < 225943500 / 4 || n_steps > 10000 {
-                // 6377 <-- christmas tree, yaay
-                return n_steps;
-            }
-        }
+    /// Steps until the robots are closest together, which is when they draw
+    /// the picture. Along each axis the positions repeat after the width or
+    /// height, so the closest step within one period is found for each axis
+    /// and both are combined with the Chinese remainder theorem.
+    fn part2(&self) -> Result<usize> {
+        let closest = |period: i32, axis: fn(&Robot) -> (i32, i32)| {
+            (0..period)
+                .min_by_key(|&step| {
+                    spread(self.robots.iter().map(|robot| {
+                        let (pos, velocity) = axis(robot);
+                        (pos + velocity * step).rem_euclid(period)
+                    }))
+                })
+                .unwrap_or(0)
+        };
+        let step_x = closest(self.width, |robot| (robot.x, robot.vx));
+        let step_y = closest(self.height, |robot| (robot.y, robot.vy));
+        let (step, _) = math::crt([
+            (step_x as i64, self.width as i64),
+            (step_y as i64, self.height as i64),
+        ])?;
+        Ok(step as usize)
     }
 
-    fn print_snapshot(&self) {
-        let mut snapshot = vec![vec!['.'; WIDTH as usize]; HEIGHT as usize];
+    fn snapshot(&self) -> String {
+        let mut snapshot = vec![vec!['.'; self.width as usize]; self.height as usize];
         for robot in &self.robots {
             snapshot[robot.y as usize][robot.x as usize] = '#';
         }
-        println!(
-            "{}",
-            snapshot
-                .iter()
-                .map(|row| row.iter().collect::<String>())
-                .collect::<Vec<String>>()
-                .join("\n")
-        );
+        snapshot
+            .iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
     }
 }
 
 impl Solution for Day14 {
-    fn part1(&self, input: &str) -> String {
-        let robots = input
-            .lines()
-            .map(|line| Robot::from(line))
-            .collect::<Vec<Robot>>();
-        Simulation::new(robots)
-            .simulate(N_STEPS)
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let robots = utils::parse_lines(input, Robot::parse)?;
+        Ok(Simulation::new(robots, self.params)
+            .after(N_STEPS)
             .eval_quadrants()
-            .to_string()
+            .into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let robots = utils::parse_lines(input, Robot::parse)?;
+        let simulation = Simulation::new(robots, self.params);
+        let n_steps = simulation.part2()?;
+        let simulation = simulation.after(n_steps);
+        Ok(Answer::Visual(format!(
+            "After {} seconds:\n{}",
+            n_steps,
+            simulation.snapshot()
+        )))
     }
 
-    fn part2(&self, input: &str) -> String {
-        let robots = input
-            .lines()
-            .map(|line| Robot::from(line))
-            .collect::<Vec<Robot>>();
-        let n_steps = Simulation::new(robots.clone()).part2();
-        Simulation::new(robots).simulate(n_steps).print_snapshot();
-        "0".to_string()
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(utils::parse_lines(input, Robot::parse).map(|robots| {
+            black_box(robots);
+        }))
+    }
+
+    fn with_params(&self, params: &aoc_core::Params) -> Option<Result<Box<dyn Solution>>> {
+        Some(
+            self.params
+                .with(params)
+                .map(|params| Box::new(Day14 { params }) as Box<dyn Solution>),
+        )
     }
 }
 
@@ -145,10 +199,14 @@ impl Solution for Day14 {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part1() {
-        // test input is 11 tiles wide and 7 tiles tall
-        let input: &str = "p=0,4 v=3,-3
+    const EXAMPLE: Day14 = Day14 {
+        params: Params {
+            width: 11,
+            height: 7,
+        },
+    };
+
+    const INPUT: &str = "p=0,4 v=3,-3
 p=6,3 v=-1,-3
 p=10,3 v=-1,2
 p=2,0 v=2,-1
@@ -160,7 +218,54 @@ p=9,3 v=2,3
 p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3";
-        let result = Day14.part1(input);
-        assert_eq!(result, 12.to_string());
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(EXAMPLE.part1(INPUT), Ok(12.into()));
+        // The same robots in the area of the real puzzle.
+        assert_eq!(Day14::PUZZLE.part1(INPUT), Ok(21.into()));
+    }
+
+    #[test]
+    fn test_part2() {
+        // Robots that gather in a 5x10 block after 6377 seconds.
+        let input: String = (0..50i32)
+            .map(|i| {
+                let (vx, vy) = (i * 37 % 201 - 100, i * 53 % 207 - 103);
+                let (x, y) = (40 + i % 5, 50 + i / 5);
+                let (x, y) = (
+                    (x - vx * 6377).rem_euclid(101),
+                    (y - vy * 6377).rem_euclid(103),
+                );
+                format!("p={},{} v={},{}\n", x, y, vx, vy)
+            })
+            .collect();
+        match Day14::PUZZLE.part2(&input) {
+            Ok(Answer::Visual(picture)) => {
+                assert!(picture.starts_with("After 6377 seconds:\n"));
+                let block_row = format!("\n{}#####.", ".".repeat(40));
+                assert_eq!(picture.matches(&block_row).count(), 10);
+            }
+            other => panic!("unexpected answer {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        let params = [("width", "11"), ("height", "7")].into_iter().collect();
+        let solver = Day14::PUZZLE.with_params(&params).unwrap().unwrap();
+        assert_eq!(solver.part1(INPUT), Ok(12.into()));
+
+        let params = [("widht", "11")].into_iter().collect();
+        assert!(Day14::PUZZLE.with_params(&params).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let result = EXAMPLE.part1("p=0,4 v=3,-3\np=6,3 w=-1,-3");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 7: expected `v=X,Y`, found \"w=-1,-3\""
+        );
     }
 }
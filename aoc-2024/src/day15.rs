@@ -1,105 +1,70 @@
-use crate::{utils::Coord, utils::Direction, Solution};
+use aoc_core::utils::{self, Coord, Direction, Grid};
+use aoc_core::{register, Answer, Error, Result, Solution};
 use std::collections::HashSet;
 
 pub struct Day15;
+register!(2024, 15, Day15);
 
-struct Grid {
-    cells: Vec<Vec<char>>,
-}
-
-impl Grid {
-    fn from(input: &str) -> Self {
-        Self {
-            cells: input.lines().map(|line| line.chars().collect()).collect(),
-        }
-    }
-
-    fn from_scaled_up(input: &str) -> Self {
-        Self {
-            cells: input
-                .lines()
-                .map(|line| {
-                    line.chars()
-                        .flat_map(|c| match c {
-                            '#' => ['#', '#'],
-                            'O' => ['[', ']'],
-                            '.' => ['.', '.'],
-                            '@' => ['@', '.'],
-                            _ => panic!("Invalid character"),
-                        })
-                        .collect()
-                })
-                .collect(),
-        }
-    }
+type Map = Grid<char>;
 
-    fn get(&self, pos: &Coord) -> char {
-        self.cells[pos.y as usize][pos.x as usize]
-    }
+fn parse_map(input: &str) -> Result<Map> {
+    Grid::parse(input, |c| match c {
+        '#' | 'O' | '.' | '@' => Ok(c),
+        _ => Err(Error::parse("one of `#O.@`", c.to_string())),
+    })
+}
 
-    fn set(&mut self, pos: &Coord, value: char) {
-        self.cells[pos.y as usize][pos.x as usize] = value;
+/// The map of part 2, where everything but the robot is twice as wide.
+fn scale_up(map: &Map) -> Map {
+    let mut wide = Grid::new(2 * map.width(), map.height(), '.');
+    for (pos, &c) in map.iter() {
+        let left = Coord {
+            x: 2 * pos.x,
+            y: pos.y,
+        };
+        let [l, r] = match c {
+            '#' => ['#', '#'],
+            'O' => ['[', ']'],
+            '@' => ['@', '.'],
+            _ => ['.', '.'],
+        };
+        wide[left] = l;
+        wide[left + Direction::Right.to_coord()] = r;
     }
+    wide
+}
 
-    fn find_robot(&self) -> Coord {
-        self.cells
-            .iter()
-            .enumerate()
-            .find_map(|(y, row)| {
-                row.iter().position(|&c| c == '@').map(|x| Coord {
-                    x: x as i32,
-                    y: y as i32,
-                })
-            })
-            .expect("Robot not found")
-    }
+fn find_robot(map: &Map) -> Result<Coord> {
+    map.find(&'@')
+        .ok_or_else(|| Error::parse("a robot `@`", "none"))
+}
 
-    fn is_wall(&self, pos: &Coord) -> bool {
-        self.get(pos) == '#'
-    }
+fn is_wall(map: &Map, pos: &Coord) -> bool {
+    map[*pos] == '#'
+}
 
-    fn calc_gps_boxes_sum(&self) -> i32 {
-        self.cells
-            .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter().enumerate().filter_map(move |(x, &c)| {
-                    if c == 'O' || c == '[' {
-                        Some(Coord {
-                            x: x as i32,
-                            y: y as i32,
-                        })
-                    } else {
-                        None
-                    }
-                })
-            })
-            .map(|coord| calc_gps(&coord))
-            .sum()
-    }
+fn calc_gps_boxes_sum(map: &Map) -> i32 {
+    map.iter()
+        .filter(|(_, &c)| c == 'O' || c == '[')
+        .map(|(coord, _)| calc_gps(&coord))
+        .sum()
 }
 
 fn read_directions(input: &str) -> Vec<Direction> {
     input
         .chars()
-        .filter_map(|c| match c {
-            '^' => Some(Direction::Up),
-            'v' => Some(Direction::Down),
-            '>' => Some(Direction::Right),
-            '<' => Some(Direction::Left),
-            _ => None,
-        })
+        .filter_map(|c| Direction::try_from(c).ok())
         .collect()
 }
 
-fn eval_step(map: &mut Grid, robot_pos: &Coord, dir: &Direction) -> Coord {
+fn eval_step(map: &mut Map, robot_pos: &Coord, dir: &Direction) -> Coord {
     let next_pos = *robot_pos + dir.to_coord();
 
-    if map.is_wall(&next_pos) {
+    if is_wall(map, &next_pos) {
         return *robot_pos;
     }
 
-    match map.get(&next_pos) {
+    match map[next_pos] {
         '.' => {
             move_robot(map, robot_pos, &next_pos);
             next_pos
@@ -110,13 +75,13 @@ fn eval_step(map: &mut Grid, robot_pos: &Coord, dir: &Direction) -> Coord {
     }
 }
 
-fn move_robot(grid: &mut Grid, from: &Coord, to: &Coord) {
-    grid.set(from, '.');
-    grid.set(to, '@');
+fn move_robot(grid: &mut Map, from: &Coord, to: &Coord) {
+    grid[*from] = '.';
+    grid[*to] = '@';
 }
 
 fn handle_box_push(
-    map: &mut Grid,
+    map: &mut Map,
     robot_pos: &Coord,
     next_pos: &Coord,
     dir: &Direction,
@@ -130,7 +95,7 @@ fn handle_box_push(
 
     let can_move = box_positions
         .iter()
-        .all(|&b| !map.is_wall(&(b + dir.to_coord())));
+        .all(|&b| !is_wall(map, &(b + dir.to_coord())));
 
     if can_move {
         // Store box symbols before modifying the map
@@ -138,19 +103,19 @@ fn handle_box_push(
             .iter()
             .map(|&pos| {
                 let new_pos = pos + dir.to_coord();
-                let symbol = map.get(&pos);
+                let symbol = map[pos];
                 (pos, new_pos, symbol)
             })
             .collect();
 
         // Clear old positions
         for (old_pos, _, _) in &box_moves {
-            map.set(&old_pos, '.');
+            map[*old_pos] = '.';
         }
 
         // Execute the moves
         for (_, new_pos, symbol) in box_moves {
-            map.set(&new_pos, symbol);
+            map[new_pos] = symbol;
         }
 
         move_robot(map, robot_pos, next_pos);
@@ -160,19 +125,19 @@ fn handle_box_push(
     }
 }
 
-fn collect_consecutive_boxes(map: &Grid, start: &Coord, dir: &Direction) -> Vec<Coord> {
+fn collect_consecutive_boxes(map: &Map, start: &Coord, dir: &Direction) -> Vec<Coord> {
     std::iter::successors(Some(*start), |pos| {
         let next_pos = *pos + dir.to_coord();
-        match map.get(&next_pos) {
-            'O' | '[' | ']' if !map.is_wall(&next_pos) => Some(next_pos),
+        match map[next_pos] {
+            'O' | '[' | ']' if !is_wall(map, &next_pos) => Some(next_pos),
             _ => None,
         }
     })
     .collect()
 }
 
-fn collect_consecutive_boxes_part2(map: &Grid, start: &Coord, dir: &Direction) -> Vec<Coord> {
-    fn collect_vertical(map: &Grid, start: &Coord, dir: &Direction) -> Vec<Coord> {
+fn collect_consecutive_boxes_part2(map: &Map, start: &Coord, dir: &Direction) -> Vec<Coord> {
+    fn collect_vertical(map: &Map, start: &Coord, dir: &Direction) -> Vec<Coord> {
         let mut boxes = Vec::new();
         let mut to_check = vec![*start];
         let mut checked = HashSet::new();
@@ -182,7 +147,7 @@ fn collect_consecutive_boxes_part2(map: &Grid, start: &Coord, dir: &Direction) -
                 continue;
             }
 
-            match map.get(&pos) {
+            match map[pos] {
                 '[' => {
                     boxes.push(pos);
                     to_check.extend([
@@ -214,28 +179,36 @@ fn calc_gps(coord: &Coord) -> i32 {
     100 * coord.y + coord.x
 }
 
+/// Splits the input into the warehouse map and the list of moves.
+fn split_input(input: &str) -> Result<(&str, &str)> {
+    let mut blocks = utils::blocks(input);
+    blocks
+        .next()
+        .zip(blocks.next())
+        .ok_or_else(|| Error::parse("a blank line between map and moves", "none"))
+}
+
 impl Solution for Day15 {
-    fn part1(&self, input: &str) -> String {
-        let mut parts = input.split("\n\n");
-        let mut grid = Grid::from(parts.next().unwrap());
-        let directions = read_directions(parts.next().unwrap());
-        let mut robot_pos = grid.find_robot();
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let (map, moves) = split_input(input)?;
+        let mut grid = parse_map(map)?;
+        let directions = read_directions(moves);
+        let mut robot_pos = find_robot(&grid)?;
         for dir in directions {
             robot_pos = eval_step(&mut grid, &robot_pos, &dir);
         }
-        grid.calc_gps_boxes_sum().to_string()
+        Ok(calc_gps_boxes_sum(&grid).into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let mut parts = input.split("\n\n");
-        let mut grid = Grid::from_scaled_up(parts.next().unwrap());
-        let directions = read_directions(parts.next().unwrap());
-        let mut robot_pos = grid.find_robot();
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let (map, moves) = split_input(input)?;
+        let mut grid = scale_up(&parse_map(map)?);
+        let directions = read_directions(moves);
+        let mut robot_pos = find_robot(&grid)?;
         for dir in directions {
             robot_pos = eval_step(&mut grid, &robot_pos, &dir);
         }
-        // 1475512
-        grid.calc_gps_boxes_sum().to_string()
+        Ok(calc_gps_boxes_sum(&grid).into())
     }
 }
 
@@ -256,7 +229,7 @@ mod tests {
 
 <^^>>>vv<v>>v<<";
         let result = Day15.part1(input);
-        assert_eq!(result, 2028.to_string());
+        assert_eq!(result, Ok(2028.into()));
     }
 
     #[test]
@@ -284,7 +257,7 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 ";
         let result = Day15.part1(input);
-        assert_eq!(result, 10092.to_string());
+        assert_eq!(result, Ok(10092.into()));
     }
 
     #[test]
@@ -312,6 +285,6 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 ";
         let result = Day15.part2(input);
-        assert_eq!(result, 9021.to_string());
+        assert_eq!(result, Ok(9021.into()));
     }
 }
@@ -1,166 +1,53 @@
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::HashSet;
 
-use crate::utils::{Coord, Direction};
-use crate::Solution;
+use aoc_core::utils::search::dijkstra;
+use aoc_core::utils::{Coord, Direction, Grid};
+use aoc_core::{register, Answer, Error, Result, Solution};
 
 pub struct Day16;
-
-#[derive(Clone, PartialEq, Eq, Hash)]
-struct State {
-    pos: Coord,
-    dir: Direction,
-    cost: i32,
-    path: Vec<Coord>,
-}
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Reverse ordering for min-heap
-        other.cost.cmp(&self.cost)
-    }
-}
-
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-struct Grid {
-    cells: Vec<Vec<char>>,
-}
-
-impl Grid {
-    fn from(input: &str) -> Self {
-        Self {
-            cells: input.lines().map(|line| line.chars().collect()).collect(),
-        }
-    }
-
-    fn get(&self, pos: &Coord) -> char {
-        self.cells[pos.y as usize][pos.x as usize]
-    }
-
-    fn is_wall(&self, pos: &Coord) -> bool {
-        self.get(pos) == '#'
-    }
-    fn find_char(&self, target: char) -> Option<Coord> {
-        for y in 0..self.cells.len() {
-            for x in 0..self.cells[0].len() {
-                if self.cells[y][x] == target {
-                    return Some(Coord {
-                        x: x as i32,
-                        y: y as i32,
-                    });
-                }
-            }
-        }
-        None
-    }
-
-    fn is_valid(&self, pos: &Coord) -> bool {
-        pos.y >= 0
-            && pos.x >= 0
-            && (pos.y as usize) < self.cells.len()
-            && (pos.x as usize) < self.cells[0].len()
-    }
-
-    fn find_optimal_path_nodes(&self) -> (usize, usize) {
-        let start = self.find_char('S').expect("No start position found");
-        let end = self.find_char('E').expect("No end position found");
-
-        let mut heap = BinaryHeap::new();
-        let mut costs = HashMap::new();
-        let mut optimal_paths = Vec::new();
-        let mut min_cost = i32::MAX;
-
-        let initial = State {
-            pos: start,
-            dir: Direction::Right,
-            cost: 0,
-            path: vec![start],
-        };
-
-        heap.push(initial);
-        costs.insert((start, Direction::Right), 0);
-
-        while let Some(State {
-            pos,
-            dir,
-            cost,
-            path,
-        }) = heap.pop()
-        {
-            if cost > min_cost {
-                break; // All optimal paths found
-            }
-
-            if pos == end {
-                if cost < min_cost {
-                    min_cost = cost;
-                    optimal_paths.clear();
-                }
-                if cost == min_cost {
-                    optimal_paths.push(path);
-                }
-                continue;
-            }
-
-            for new_dir in &[
-                Direction::Up,
-                Direction::Right,
-                Direction::Down,
-                Direction::Left,
-            ] {
-                let new_pos = pos + new_dir.to_coord();
-
-                if !self.is_valid(&new_pos) || self.is_wall(&new_pos) {
-                    continue;
-                }
-
-                let turn_cost = if *new_dir == dir { 0 } else { 1000 };
-                let move_cost = 1;
-                let new_cost = cost + turn_cost + move_cost;
-
-                if new_cost > min_cost {
-                    continue;
-                }
-
-                let better = costs
-                    .get(&(new_pos, *new_dir))
-                    .map_or(true, |&c| new_cost <= c);
-
-                if better {
-                    costs.insert((new_pos, *new_dir), new_cost);
-                    let mut new_path = path.clone();
-                    new_path.push(new_pos);
-                    heap.push(State {
-                        pos: new_pos,
-                        dir: *new_dir,
-                        cost: new_cost,
-                        path: new_path,
-                    });
-                }
-            }
-        }
-
-        let unique_nodes: HashSet<_> = optimal_paths
-            .into_iter()
-            .flat_map(|path| path.into_iter())
-            .filter(|pos| self.get(pos) == '.')
-            .collect();
-
-        (min_cost as usize, unique_nodes.len() + 2)
-    }
+register!(2024, 16, Day16);
+
+fn find_optimal_path_nodes(maze: &Grid<char>) -> Result<(usize, usize)> {
+    let start = maze
+        .find(&'S')
+        .ok_or_else(|| Error::parse("a start `S`", "none"))?;
+    let end = maze
+        .find(&'E')
+        .ok_or_else(|| Error::parse("an end `E`", "none"))?;
+
+    // The reindeer's state is where it stands and which way it faces.
+    let neighbors = |&(pos, dir): &(Coord, Direction)| {
+        Direction::ALL.into_iter().filter_map(move |new_dir| {
+            let new_pos = pos + new_dir.to_coord();
+            let turn_cost = if new_dir == dir { 0 } else { 1000 };
+            maze.get(new_pos)
+                .is_some_and(|&c| c != '#')
+                .then_some(((new_pos, new_dir), turn_cost + 1))
+        })
+    };
+    let paths = dijkstra([(start, Direction::Right)], neighbors, |&(pos, _)| {
+        pos == end
+    });
+    let min_cost = paths
+        .goal_distance()
+        .ok_or_else(|| Error::solve("no path from start to end"))?;
+
+    let unique_nodes: HashSet<Coord> = paths
+        .nodes_on_paths(&paths.goals)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+
+    Ok((min_cost, unique_nodes.len()))
 }
 
 impl Solution for Day16 {
-    fn part1(&self, input: &str) -> String {
-        Grid::from(input).find_optimal_path_nodes().0.to_string()
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(find_optimal_path_nodes(&Grid::parse(input, Ok)?)?.0.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        Grid::from(input).find_optimal_path_nodes().1.to_string()
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(find_optimal_path_nodes(&Grid::parse(input, Ok)?)?.1.into())
     }
 }
 
@@ -186,7 +73,7 @@ mod tests {
 #S..#.....#...#
 ###############";
         let result = Day16.part1(input);
-        assert_eq!(result, 7036.to_string());
+        assert_eq!(result, Ok(7036.into()));
     }
 
     #[test]
@@ -209,7 +96,7 @@ mod tests {
 #S#.............#
 #################";
         let result = Day16.part1(input);
-        assert_eq!(result, 11048.to_string());
+        assert_eq!(result, Ok(11048.into()));
     }
 
     #[test]
@@ -230,7 +117,7 @@ mod tests {
 #S..#.....#...#
 ###############";
         let result = Day16.part2(input);
-        assert_eq!(result, 45.to_string());
+        assert_eq!(result, Ok(45.into()));
     }
 
     #[test]
@@ -253,6 +140,6 @@ mod tests {
 #S#.............#
 #################";
         let result = Day16.part2(input);
-        assert_eq!(result, 64.to_string());
+        assert_eq!(result, Ok(64.into()));
     }
 }
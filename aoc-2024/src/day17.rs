@@ -1,6 +1,9 @@
-use crate::{utils, Solution};
+use std::hint::black_box;
+
+use aoc_core::{register, utils, Answer, Error, Result, Solution};
 
 pub struct Day17;
+register!(2024, 17, Day17);
 
 #[derive(Debug)]
 struct Computer {
@@ -24,86 +27,158 @@ impl Computer {
         }
     }
 
-    fn get_combo_value(&self, operand: i64) -> i64 {
+    fn get_combo_value(&self, operand: i64) -> Result<i64> {
         match operand {
-            0..=3 => operand,
-            4 => self.register_a,
-            5 => self.register_b,
-            6 => self.register_c,
-            _ => panic!("Invalid combo operand: {}", operand),
+            0..=3 => Ok(operand),
+            4 => Ok(self.register_a),
+            5 => Ok(self.register_b),
+            6 => Ok(self.register_c),
+            _ => Err(Error::solve(format!("invalid combo operand: {}", operand))),
         }
     }
 
-    fn run(&mut self) {
+    /// Register A shifted right by the combo operand, as adv, bdv and cdv do.
+    fn shifted_a(&self, operand: i64) -> Result<i64> {
+        let power = self.get_combo_value(operand)?;
+        u32::try_from(power)
+            .ok()
+            .and_then(|power| self.register_a.checked_shr(power))
+            .ok_or_else(|| Error::solve(format!("shift by {} is out of range", power)))
+    }
+
+    fn run(&mut self) -> Result<()> {
         while self.instruction_ptr < self.program.len() {
             let opcode = self.program[self.instruction_ptr];
-            let operand = self.program[self.instruction_ptr + 1];
+            let operand = *self
+                .program
+                .get(self.instruction_ptr + 1)
+                .ok_or_else(|| Error::solve(format!("opcode {} without operand", opcode)))?;
 
             match opcode {
-                0 => { // adv
-                    let power = self.get_combo_value(operand);
-                    self.register_a /= 1 << power;
+                0 => {
+                    // adv
+                    self.register_a = self.shifted_a(operand)?;
                 }
-                1 => { // bxl
+                1 => {
+                    // bxl
                     self.register_b ^= operand;
                 }
-                2 => { // bst
-                    self.register_b = self.get_combo_value(operand) % 8;
+                2 => {
+                    // bst
+                    self.register_b = self.get_combo_value(operand)? % 8;
                 }
-                3 => { // jnz
+                3 => {
+                    // jnz
                     if self.register_a != 0 {
                         self.instruction_ptr = operand as usize;
                         continue;
                     }
                 }
-                4 => { // bxc
+                4 => {
+                    // bxc
                     self.register_b ^= self.register_c;
                 }
-                5 => { // out
-                    let value = self.get_combo_value(operand) % 8;
+                5 => {
+                    // out
+                    let value = self.get_combo_value(operand)? % 8;
                     self.output.push(value);
                 }
-                6 => { // bdv
-                    let power = self.get_combo_value(operand);
-                    self.register_b = self.register_a / (1 << power);
+                6 => {
+                    // bdv
+                    self.register_b = self.shifted_a(operand)?;
                 }
-                7 => { // cdv
-                    let power = self.get_combo_value(operand);
-                    self.register_c = self.register_a / (1 << power);
+                7 => {
+                    // cdv
+                    self.register_c = self.shifted_a(operand)?;
                 }
-                _ => panic!("Invalid opcode: {}", opcode),
+                _ => return Err(Error::solve(format!("invalid opcode: {}", opcode))),
             }
             self.instruction_ptr += 2;
         }
+        Ok(())
     }
 }
 
+/// Value of the next non-empty line, which has to look like `label: value`.
+fn parse_field<'a>(
+    input: &'a str,
+    lines: &mut impl Iterator<Item = &'a str>,
+    label: &str,
+) -> Result<&'a str> {
+    let line = lines
+        .find(|line| !line.is_empty())
+        .ok_or_else(|| Error::parse(format!("`{}: ...`", label), "end of input"))?;
+    utils::header(line, label).map_err(|err| err.within(input, line))
+}
+
+fn parse_computer(input: &str) -> Result<Computer> {
+    let mut lines = input.lines();
+    let mut register = |label| {
+        let value = parse_field(input, &mut lines, label)?;
+        utils::parse_token(input, value, "integer")
+    };
+    let register_a = register("Register A")?;
+    let register_b = register("Register B")?;
+    let register_c = register("Register C")?;
+
+    let program = parse_field(input, &mut lines, "Program")?;
+    let program = utils::integers(program).map_err(|err| err.within(input, program))?;
+
+    Ok(Computer::new(register_a, register_b, register_c, program))
+}
+
+/// The program consumes register A three bits per output value, so the value
+/// of A is built up one octal digit at a time, matching the program from its
+/// last instruction backwards.
+fn find_quine_register(
+    computer: &Computer,
+    register_a: i64,
+    n_matched: usize,
+) -> Result<Option<i64>> {
+    let program = &computer.program;
+    if n_matched == program.len() {
+        return Ok(Some(register_a));
+    }
+    for digit in 0..8 {
+        let candidate = register_a * 8 + digit;
+        if candidate == 0 {
+            continue;
+        }
+        let mut trial = Computer::new(
+            candidate,
+            computer.register_b,
+            computer.register_c,
+            program.clone(),
+        );
+        trial.run()?;
+        if trial.output == program[program.len() - n_matched - 1..] {
+            if let Some(res) = find_quine_register(computer, candidate, n_matched + 1)? {
+                return Ok(Some(res));
+            }
+        }
+    }
+    Ok(None)
+}
+
 impl Solution for Day17 {
-    fn part1(&self, input: &str) -> String {
-        let lines: Vec<&str> = input.lines().collect();
-        let register_a = lines[0].split(": ").nth(1).unwrap().parse().unwrap();
-        let register_b = lines[1].split(": ").nth(1).unwrap().parse().unwrap();
-        let register_c = lines[2].split(": ").nth(1).unwrap().parse().unwrap();
-        
-        let program: Vec<i64> = lines[4]
-            .split(": ")
-            .nth(1)
-            .unwrap()
-            .split(',')
-            .map(|n| n.parse().unwrap())
-            .collect();
-
-        let mut computer = Computer::new(register_a, register_b, register_c, program);
-        computer.run();
-        
-        computer.output.iter()
-            .map(|n| n.to_string())
-            .collect::<Vec<String>>()
-            .join(",")
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let mut computer = parse_computer(input)?;
+        computer.run()?;
+
+        Ok(Answer::List(computer.output))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let computer = parse_computer(input)?;
+        find_quine_register(&computer, 0, 0)?
+            .map(Answer::from)
+            .ok_or_else(|| Error::solve("no value of register A reproduces the program"))
     }
 
-    fn part2(&self, input: &str) -> String {
-        unimplemented!("Part 2 not implemented yet")
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse_computer(input).map(|computer| {
+            black_box(computer);
+        }))
     }
 }
 
@@ -119,7 +194,7 @@ Register C: 0
 
 Program: 0,1,5,4,3,0";
         let result = Day17.part1(input);
-        assert_eq!(result, "4,6,3,5,6,3,5,2,1,0".to_string());
+        assert_eq!(result, Ok(Answer::List(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0])));
     }
 
     #[test]
@@ -130,6 +205,16 @@ Register C: 0
 
 Program: 0,3,5,4,3,0";
         let result = Day17.part2(input);
-        assert_eq!(result, "117440");
+        assert_eq!(result, Ok(117440.into()));
+    }
+
+    #[test]
+    fn test_shift_out_of_range() {
+        let input: &str = "Register A: 1
+Register B: 64
+Register C: 0
+
+Program: 6,5";
+        assert!(Day17.part1(input).is_err());
     }
 }
@@ -1,147 +1,155 @@
-use crate::{utils::Coord, Solution};
-use std::str::FromStr;
+use aoc_core::utils::dsu::Dsu;
+use aoc_core::utils::{self, search, Coord, Grid};
+use aoc_core::{register, Answer, Error, Result, Solution};
+use std::hint::black_box;
 
-pub struct Day18;
-
-#[cfg(test)]
-const N_SIZE: usize = 7;
-#[cfg(not(test))]
-const N_SIZE: usize = 71;
+pub struct Day18 {
+    params: Params,
+}
+register!(2024, 18, Day18::PUZZLE);
 
-#[cfg(test)]
-const N_STEPS: usize = 12;
-#[cfg(not(test))]
-const N_STEPS: usize = 1024;
+impl Day18 {
+    pub const PUZZLE: Day18 = Day18 {
+        params: Params {
+            size: 71,
+            bytes: 1024,
+        },
+    };
+}
 
-struct Grid {
-    cells: Vec<Vec<char>>,
+/// Size of the memory space and number of bytes fallen in part 1, 7 and 12
+/// in the example.
+#[derive(Copy, Clone, Debug)]
+struct Params {
+    size: usize,
+    bytes: usize,
 }
 
-impl Grid {
-    fn new(obstacles: &[Coord]) -> Self {
-        let mut cells = vec![vec!['.'; N_SIZE]; N_SIZE];
-        for &Coord { x, y } in obstacles {
-            cells[y as usize][x as usize] = '#';
+impl Params {
+    fn with(mut self, given: &aoc_core::Params) -> Result<Self> {
+        given.check_keys(&["size", "bytes"])?;
+        given.apply("size", &mut self.size)?;
+        given.apply("bytes", &mut self.bytes)?;
+        if self.size == 0 {
+            return Err(Error::input("the size must be positive"));
         }
-        Self { cells }
+        Ok(self)
     }
+}
 
-    fn find_path(&self) -> Vec<Coord> {
-        let start = Coord { x: 0, y: 0 };
-        let end = Coord {
-            x: N_SIZE as i32 - 1,
-            y: N_SIZE as i32 - 1,
-        };
+struct Memory {
+    cells: Grid<char>,
+}
 
-        let mut queue = vec![start];
-        let mut visited = vec![vec![false; N_SIZE]; N_SIZE];
-        let mut parent = vec![vec![Coord { x: -1, y: -1 }; N_SIZE]; N_SIZE];
-        visited[start.y as usize][start.x as usize] = true;
-
-        let mut found_end = false;
-        while !queue.is_empty() && !found_end {
-            let current = queue.remove(0);
-
-            for dy in -1..=1 {
-                for dx in -1..=1 {
-                    // skip diagonal movement
-                    if (dx != 0 && dy != 0) || (dx == 0 && dy == 0) {
-                        continue;
-                    }
-
-                    let next = Coord {
-                        x: current.x + dx,
-                        y: current.y + dy,
-                    };
-
-                    if next.x < 0
-                        || next.x >= N_SIZE as i32
-                        || next.y < 0
-                        || next.y >= N_SIZE as i32
-                    {
-                        continue;
-                    }
-                    if self.cells[next.y as usize][next.x as usize] == '#' {
-                        continue;
-                    }
-                    if visited[next.y as usize][next.x as usize] {
-                        continue;
-                    }
-
-                    visited[next.y as usize][next.x as usize] = true;
-                    parent[next.y as usize][next.x as usize] = current;
-                    queue.push(next);
-
-                    if next == end {
-                        found_end = true;
-                        break;
-                    }
-                }
-                if found_end {
-                    break;
-                }
+impl Memory {
+    fn new(obstacles: &[Coord], size: usize) -> Result<Self> {
+        let mut cells = Grid::new(size, size, '.');
+        for &pos in obstacles {
+            match cells.get_mut(pos) {
+                Some(cell) => *cell = '#',
+                None => return Err(outside(pos, size)),
             }
         }
+        Ok(Self { cells })
+    }
 
-        if !found_end {
-            return Vec::new();
-        }
+    /// Number of steps from the top left to the bottom right corner.
+    fn shortest_path(&self) -> Option<usize> {
+        let end = Coord {
+            x: self.cells.width() as i32 - 1,
+            y: self.cells.height() as i32 - 1,
+        };
+        let open_neighbors = |&pos: &Coord| {
+            pos.neighbors4()
+                .filter(|&next| self.cells.get(next).is_some_and(|&c| c != '#'))
+        };
+        search::bfs([Coord::ORIGIN], open_neighbors, |&pos| pos == end).goal_distance()
+    }
+}
 
-        // Reconstruct path from end to start
-        let mut path = Vec::new();
-        let mut current = end;
+fn outside(pos: Coord, size: usize) -> Error {
+    Error::input(format!(
+        "byte {},{} falls outside the memory space of size {}",
+        pos.x, pos.y, size
+    ))
+}
 
-        while current != start {
-            path.push(current);
-            current = parent[current.y as usize][current.x as usize];
-            if current.x == -1 && current.y == -1 {
-                return Vec::new();
-            }
+/// The first byte that cuts the top left corner off from the bottom right.
+///
+/// Starts with all bytes fallen and takes them away again from the last,
+/// joining each freed cell with the free cells around it, until the corners
+/// are connected. The byte taken away then was the one that cut them off.
+fn first_blocking(coords: &[Coord], size: usize) -> Result<Option<Coord>> {
+    // How many bytes are still on each cell.
+    let mut fallen = Grid::new(size, size, 0usize);
+    for &pos in coords {
+        *fallen.get_mut(pos).ok_or_else(|| outside(pos, size))? += 1;
+    }
+
+    let idx = |pos: Coord| pos.y as usize * size + pos.x as usize;
+    let mut dsu = Dsu::new(size * size);
+    let free = |fallen: &Grid<usize>, pos: Coord| fallen.get(pos) == Some(&0);
+    let join = |dsu: &mut Dsu, fallen: &Grid<usize>, pos: Coord| {
+        for next in pos.neighbors4().filter(|&next| free(fallen, next)) {
+            dsu.union(idx(pos), idx(next));
         }
+    };
+    let (start, end) = (Coord::ORIGIN, Coord::new(size as i32 - 1, size as i32 - 1));
+    let connected = |dsu: &mut Dsu, fallen: &Grid<usize>| {
+        free(fallen, start) && free(fallen, end) && dsu.same(idx(start), idx(end))
+    };
 
-        path.push(start);
-        path.reverse();
-        path
+    for pos in fallen.find_all(&0) {
+        join(&mut dsu, &fallen, pos);
     }
-}
-
-impl FromStr for Coord {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s.split_once(',').ok_or("ParseCoordError".to_owned())?;
-        Ok(Coord {
-            x: x.parse().map_err(|_| "ParseCoordError".to_owned())?,
-            y: y.parse().map_err(|_| "ParseCoordError".to_owned())?,
-        })
+    if connected(&mut dsu, &fallen) {
+        return Ok(None);
+    }
+    for &pos in coords.iter().rev() {
+        fallen[pos] -= 1;
+        if fallen[pos] == 0 {
+            join(&mut dsu, &fallen, pos);
+            if connected(&mut dsu, &fallen) {
+                return Ok(Some(pos));
+            }
+        }
     }
+    unreachable!("the corners are connected without any bytes")
 }
 
 impl Solution for Day18 {
-    fn part1(&self, input: &str) -> String {
-        let coords: Vec<Coord> = input
-            .lines()
-            .take(N_STEPS)
-            .map(|line| line.parse().expect("error parsing"))
-            .collect();
-        let grid = Grid::new(&coords);
-        (grid.find_path().len() - 1).to_string()
+    fn part1(&self, input: &str) -> Result<Answer> {
+        let coords = utils::parse_lines(input, str::parse)?;
+        let bytes = self.params.bytes.min(coords.len());
+        let grid = Memory::new(&coords[..bytes], self.params.size)?;
+        match grid.shortest_path() {
+            Some(steps) => Ok(steps.into()),
+            None => Err(Error::solve("no path to the exit")),
+        }
     }
 
-    fn part2(&self, input: &str) -> String {
-        let coords: Vec<Coord> = input
-            .lines()
-            .map(|line| line.parse().expect("error parsing"))
-            .collect();
-        let res = "".to_owned();
-        for i in N_STEPS..coords.len() {
-            let coords = &coords[..i as usize];
-            let grid = Grid::new(&coords);
-            if grid.find_path().len() == 0 {
-                let last = coords.last().unwrap();
-                return format!("{},{}", last.x, last.y);
-            }
+    fn part2(&self, input: &str) -> Result<Answer> {
+        let coords: Vec<Coord> = utils::parse_lines(input, str::parse)?;
+        match first_blocking(&coords, self.params.size)? {
+            Some(pos) => Ok(Answer::List(vec![pos.x as i64, pos.y as i64])),
+            None => Err(Error::solve("no byte blocks the exit")),
         }
-        res
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(
+            utils::parse_lines(input, str::parse::<Coord>).map(|coords| {
+                black_box(coords);
+            }),
+        )
+    }
+
+    fn with_params(&self, params: &aoc_core::Params) -> Option<Result<Box<dyn Solution>>> {
+        Some(
+            self.params
+                .with(params)
+                .map(|params| Box::new(Day18 { params }) as Box<dyn Solution>),
+        )
     }
 }
 
@@ -149,6 +157,10 @@ impl Solution for Day18 {
 mod tests {
     use super::*;
 
+    const EXAMPLE: Day18 = Day18 {
+        params: Params { size: 7, bytes: 12 },
+    };
+
     #[test]
     fn test_part1() {
         let input: &str = "5,4
@@ -176,8 +188,8 @@ mod tests {
 0,5
 1,6
 2,0";
-        let result = Day18.part1(input);
-        assert_eq!(result, 22.to_string());
+        let result = EXAMPLE.part1(input);
+        assert_eq!(result, Ok(22.into()));
     }
 
     #[test]
@@ -207,7 +219,39 @@ mod tests {
 0,5
 1,6
 2,0";
-        let result = Day18.part2(input);
-        assert_eq!(result, "6,1".to_string());
+        let result = EXAMPLE.part2(input);
+        assert_eq!(result, Ok(Answer::List(vec![6, 1])));
+    }
+
+    #[test]
+    fn test_first_blocking() {
+        // Compare with searching again after every byte, on pseudo-random
+        // bytes that also land on the corners and on each other.
+        let mut seed = 7u32;
+        let coords: Vec<Coord> = (0..60)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                let n = (seed >> 16) as i32 % 36;
+                Coord::new(n % 6, n / 6)
+            })
+            .collect();
+        let expected = (1..=coords.len())
+            .find(|&i| {
+                Memory::new(&coords[..i], 6)
+                    .unwrap()
+                    .shortest_path()
+                    .is_none()
+            })
+            .map(|i| coords[i - 1]);
+        assert!(expected.is_some());
+        assert_eq!(first_blocking(&coords, 6), Ok(expected));
+
+        assert_eq!(first_blocking(&coords[..1], 6), Ok(None));
+        assert_eq!(
+            first_blocking(&[Coord::new(3, 3)], 3)
+                .unwrap_err()
+                .to_string(),
+            "byte 3,3 falls outside the memory space of size 3"
+        );
     }
 }
@@ -1,13 +1,14 @@
-use crate::{utils, Solution};
+use aoc_core::{register, utils, Answer, Result, Solution};
 
 pub struct DayXY;
+register!(2024, XY, DayXY);
 
 impl Solution for DayXY {
-    fn part1(&self, input: &str) -> String {
+    fn part1(&self, input: &str) -> Result<Answer> {
         unimplemented!("Part 1 not implemented yet")
     }
 
-    fn part2(&self, input: &str) -> String {
+    fn part2(&self, input: &str) -> Result<Answer> {
         unimplemented!("Part 2 not implemented yet")
     }
 }
@@ -20,13 +21,13 @@ mod tests {
     fn test_part1() {
         let input: &str = "";
         let result = DayXY.part1(input);
-        assert_eq!(result, 0.to_string());
+        assert_eq!(result, Ok(0.into()));
     }
 
     #[test]
     fn test_part2() {
         let input: &str = "";
         let result = DayXY.part2(input);
-        assert_eq!(result, 0.to_string());
+        assert_eq!(result, Ok(0.into()));
     }
 }
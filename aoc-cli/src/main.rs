@@ -0,0 +1,583 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use aoc_core::answers::{self, Answers, Verdict};
+use aoc_core::bench::{self, BenchConfig};
+use aoc_core::example::Example;
+use aoc_core::fetch::FetchConfig;
+use aoc_core::input::{DataDir, InputConfig, InputSource};
+use aoc_core::runner::{self, Part, PartResult, RunConfig};
+use aoc_core::submit::{self, Outcome, Response};
+use aoc_core::watch::{self, Change, Watcher};
+use aoc_core::{report, scaffold, Answer, Params};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+// The year crates are only needed for the solvers they register.
+use aoc_2023 as _;
+use aoc_2024 as _;
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum PartArg {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartArg {
+    fn parts(&self) -> &'static [Part] {
+        match self {
+            PartArg::One => &[Part::One],
+            PartArg::Two => &[Part::Two],
+            PartArg::Both => &Part::BOTH,
+        }
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|time| !time.is_zero())
+        .ok_or_else(|| format!("expected a positive number of seconds, found {:?}", s))
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    Params::parse_arg(s).map_err(|err| err.to_string())
+}
+
+fn latest_year() -> u16 {
+    runner::implemented_years()
+        .last()
+        .copied()
+        .expect("no solvers registered")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, ValueEnum)]
+enum Format {
+    /// Progress lines and a summary table
+    Table,
+    /// A JSON document, see the `report` module of aoc-core for the schema
+    Json,
+    /// One CSV row per part, with the same fields as JSON
+    Csv,
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Puzzle year, defaults to the latest implemented one
+    #[arg(short, long)]
+    year: Option<u16>,
+    #[arg(short, long, num_args = 1.., required_unless_present = "all")]
+    days: Vec<u8>,
+    /// Run every implemented day
+    #[arg(short, long, conflicts_with = "days")]
+    all: bool,
+    #[arg(short, long, value_enum, default_value_t = PartArg::Both)]
+    part: PartArg,
+    /// Read the input from a file instead, `-` for stdin
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Use the day's numbered example instead of the puzzle input
+    #[arg(short, long, conflicts_with = "input", value_parser = clap::value_parser!(u32).range(1..))]
+    example: Option<u32>,
+    /// Set a puzzle parameter such as a grid size, overriding the example's
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Directory with a subdirectory of inputs per year [env: AOC_DATA_DIR]
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+}
+
+fn data_dir(arg: &Option<PathBuf>) -> DataDir {
+    arg.clone().map_or_else(DataDir::from_env, DataDir::new)
+}
+
+impl Selection {
+    fn year(&self) -> u16 {
+        self.year.unwrap_or_else(latest_year)
+    }
+
+    fn days(&self) -> Vec<u8> {
+        if self.all {
+            runner::implemented_days(self.year())
+        } else {
+            self.days.clone()
+        }
+    }
+
+    fn input(&self) -> Result<InputConfig, String> {
+        let input = input_config(&self.input, self.example, &self.params, &self.data_dir)?;
+        if input.source.is_fixed() && self.days.len() > 1 {
+            return Err("--input can only be used with a single day".to_string());
+        }
+        Ok(input)
+    }
+}
+
+fn input_config(
+    input: &Option<PathBuf>,
+    example: Option<u32>,
+    params: &[(String, String)],
+    dir: &Option<PathBuf>,
+) -> Result<InputConfig, String> {
+    let source = match (input, example) {
+        (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+        (Some(path), _) => InputSource::File(path.clone()),
+        (None, Some(n)) => InputSource::Example(n),
+        (None, None) => InputSource::Puzzle,
+    };
+    let fetch = FetchConfig::load().map_err(|err| err.to_string())?;
+    Ok(InputConfig {
+        data_dir: data_dir(dir),
+        source,
+        fetch: Some(fetch),
+        params: params.iter().cloned().collect(),
+    })
+}
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    selection: Selection,
+    /// Store the answers in the year's answers file
+    #[arg(long, conflicts_with_all = ["verify", "input", "example"])]
+    record: bool,
+    /// Check the answers against the year's answers file
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    verify: bool,
+    /// Number of days to run at the same time
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+    /// Seconds after which a part is reported as TIMEOUT
+    #[arg(short, long, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Output format, anything but `table` prints only the report to stdout
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Time the selected days over repeated runs
+    Bench(BenchArgs),
+    /// Create the solver of a new day from the day template
+    New(NewArgs),
+    /// Submit the answer of a part to the puzzle server
+    Submit(SubmitArgs),
+    /// Re-run a day whenever its input, examples or source change
+    ///
+    /// A changed source ends the command, as the binary has to be rebuilt.
+    /// To have that done as well, run it in a loop, e.g.
+    /// `while cargo run -q -- watch -d 15; do :; done`.
+    Watch(WatchArgs),
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Number of timed runs per part
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Number of untimed runs before measuring
+    #[arg(short, long, default_value_t = 2)]
+    warmup: u32,
+    /// Save the results to a file
+    #[arg(long)]
+    save: Option<PathBuf>,
+    /// Compare the results against a file saved by an earlier run
+    #[arg(long)]
+    compare: Option<PathBuf>,
+    /// Slowdown of the median, in percent, reported as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Puzzle year, defaults to the latest implemented one
+    #[arg(short, long)]
+    year: Option<u16>,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=runner::MAX_DAY as i64))]
+    day: u8,
+    /// Example file whose input and expected answers go into the tests
+    #[arg(short, long)]
+    example: Option<PathBuf>,
+    /// Directory with a subdirectory of inputs per year [env: AOC_DATA_DIR]
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Puzzle year, defaults to the latest implemented one
+    #[arg(short, long)]
+    year: Option<u16>,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=runner::MAX_DAY as i64))]
+    day: u8,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Submit this answer instead of the one the solver computes
+    #[arg(short, long)]
+    answer: Option<String>,
+    /// Directory with a subdirectory of inputs per year [env: AOC_DATA_DIR]
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Puzzle year, defaults to the latest implemented one
+    #[arg(short, long)]
+    year: Option<u16>,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=runner::MAX_DAY as i64))]
+    day: u8,
+    #[arg(short, long, value_enum, default_value_t = PartArg::Both)]
+    part: PartArg,
+    /// Read the input from this file instead
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Use the day's numbered example instead of the puzzle input
+    #[arg(short, long, conflicts_with = "input", value_parser = clap::value_parser!(u32).range(1..))]
+    example: Option<u32>,
+    /// Set a puzzle parameter such as a grid size, overriding the example's
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Directory with a subdirectory of inputs per year [env: AOC_DATA_DIR]
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+    /// Seconds between two checks for changes
+    #[arg(long, default_value = "0.5", value_parser = parse_seconds)]
+    interval: Duration,
+    /// Seconds after which a part is reported as TIMEOUT
+    #[arg(short, long, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+}
+
+fn print_progress(res: &PartResult) {
+    match &res.answer {
+        Some(answer) if answer.is_visual() => println!(
+            "Day {} part {} needs inspection ({}):\n{}",
+            res.day,
+            res.part,
+            runner::format_duration(res.time),
+            answer
+        ),
+        Some(answer) => println!(
+            "Day {} part {}: {} ({})",
+            res.day,
+            res.part,
+            answer,
+            runner::format_duration(res.time)
+        ),
+        None => println!("Day {} part {}: {}", res.day, res.part, res.status),
+    }
+}
+
+fn run(cli: &Cli) -> ExitCode {
+    let selection = &cli.selection;
+    let year = selection.year();
+    let input = match selection.input() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let config = RunConfig {
+        jobs: cli.jobs as usize,
+        timeout: cli.timeout,
+    };
+    let days = selection.days();
+    let is_table = cli.format == Format::Table;
+    let results = runner::run_days(year, &days, selection.part.parts(), &input, config, |res| {
+        if is_table {
+            print_progress(res);
+        }
+    });
+
+    let written = match cli.format {
+        Format::Table => {
+            println!();
+            runner::print_summary(&results);
+            Ok(())
+        }
+        Format::Json => report::write_json(&mut io::stdout().lock(), &results),
+        Format::Csv => report::write_csv(&mut io::stdout().lock(), &results),
+    };
+    if let Err(err) = written {
+        eprintln!("Error writing the report: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    // Keep stdout for the report unless it's meant for humans anyway.
+    let mut notes: Box<dyn Write> = if is_table {
+        Box::new(io::stdout())
+    } else {
+        Box::new(io::stderr())
+    };
+    if cli.record || cli.verify {
+        let path = answers::answers_path(year);
+        let mut known = match Answers::load(&path) {
+            Ok(known) => known,
+            Err(err) => {
+                eprintln!("Error loading answers from {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+        };
+        if cli.record {
+            let recorded = known.record(&results);
+            if let Err(err) = known.save(&path) {
+                eprintln!("Error saving answers to {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+            let _ = writeln!(
+                notes,
+                "\nRecorded {} answers in {}",
+                recorded,
+                path.display()
+            );
+        } else {
+            let verdicts: Vec<Verdict> = results
+                .iter()
+                .map(|res| answers::verify(&known, res))
+                .collect();
+            let _ = writeln!(notes)
+                .and_then(|_| answers::write_verification(&mut notes, &results, &verdicts));
+            if verdicts.iter().any(|v| matches!(v, Verdict::Fail { .. })) {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench(args: &BenchArgs) -> ExitCode {
+    let config = BenchConfig {
+        runs: args.runs as usize,
+        warmup: args.warmup as usize,
+    };
+    let year = args.selection.year();
+    let input = match args.selection.input() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let results: Vec<_> = args
+        .selection
+        .days()
+        .into_iter()
+        .flat_map(|day| bench::bench_day(year, day, args.selection.part.parts(), &input, config))
+        .collect();
+    bench::print_report(&results);
+
+    if let Some(path) = &args.save {
+        if let Err(err) = bench::save(path, &results) {
+            eprintln!("Error saving results to {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(path) = &args.compare {
+        let baseline = match bench::load(path) {
+            Ok(baseline) => baseline,
+            Err(err) => {
+                eprintln!("Error loading results from {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let comparisons = bench::compare(&baseline, &results, args.threshold);
+        println!();
+        bench::print_comparison(&comparisons);
+        if comparisons.iter().any(|cmp| cmp.regression) {
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn new(args: &NewArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(latest_year);
+    let example = match args.example.as_deref().map(Example::load).transpose() {
+        Ok(example) => example,
+        Err(err) => {
+            eprintln!("Error loading example: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match scaffold::new_day(year, args.day, &data_dir(&args.data_dir), example.as_ref()) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error creating day {} of {}: {}", args.day, year, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(args: &SubmitArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(latest_year);
+    let part = Part::from_number(args.part).expect("part is 1 or 2");
+    let config = match FetchConfig::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let answer = match &args.answer {
+        Some(answer) => Answer::parse(answer),
+        None => {
+            let input = InputConfig {
+                data_dir: data_dir(&args.data_dir),
+                source: InputSource::Puzzle,
+                fetch: Some(config.clone()),
+                params: Params::default(),
+            };
+            let res = runner::run_day(year, args.day, &[part], &input, None).remove(0);
+            match res.answer {
+                Some(answer) if answer.is_visual() => {
+                    eprintln!(
+                        "Day {} part {} needs inspection, pass the answer with --answer:\n{}",
+                        args.day, part, answer
+                    );
+                    return ExitCode::FAILURE;
+                }
+                Some(answer) => answer,
+                None => {
+                    eprintln!("Day {} part {}: {}", args.day, part, res.status);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    println!("Submitting {} for day {} part {}", answer, args.day, part);
+    match submit::submit(&config, year, args.day, part, &answer) {
+        Ok(Outcome::Answered(Response::Correct)) => {
+            println!("{}", Response::Correct);
+            let path = answers::answers_path(year);
+            let saved = Answers::load(&path).and_then(|mut known| {
+                known.insert(args.day, part, answer);
+                known.save(&path)
+            });
+            if let Err(err) = saved {
+                eprintln!("Error recording the answer in {}: {}", path.display(), err);
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(Outcome::Answered(response)) => {
+            println!("{}", response);
+            ExitCode::FAILURE
+        }
+        Ok(Outcome::Refused(refusal)) => {
+            eprintln!("Not submitted, {}", refusal);
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch(args: &WatchArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(latest_year);
+    if args
+        .input
+        .as_ref()
+        .is_some_and(|path| path.as_os_str() == "-")
+    {
+        eprintln!("Error: stdin can't be watched, pass a file to --input");
+        return ExitCode::FAILURE;
+    }
+    let input = match input_config(&args.input, args.example, &args.params, &args.data_dir) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut watcher = Watcher::new(year, args.day, &input);
+    let source = watch::source_path(year, args.day);
+    println!(
+        "Watching day {} of {}, press Ctrl-C to stop",
+        args.day, year
+    );
+    for path in watcher.paths() {
+        println!("  {}", path.display());
+    }
+
+    let mut previous: Vec<PartResult> = Vec::new();
+    let mut changed = Vec::new();
+    loop {
+        if changed.contains(&source) {
+            println!("\n{} changed, rebuild to run it", source.display());
+            return ExitCode::SUCCESS;
+        }
+        if previous.is_empty() || !changed.is_empty() {
+            println!();
+            for path in &changed {
+                println!("{} changed", path.display());
+            }
+            let results = runner::run_day(year, args.day, args.part.parts(), &input, args.timeout);
+            for res in &results {
+                let change = Change::between(previous.iter().find(|p| p.part == res.part), res);
+                match &res.answer {
+                    Some(answer) if answer.is_visual() => println!(
+                        "Day {} part {} needs inspection ({}, {}):\n{}",
+                        res.day,
+                        res.part,
+                        change,
+                        runner::format_duration(res.time),
+                        answer
+                    ),
+                    Some(answer) => println!(
+                        "Day {} part {}: {} ({}, {})",
+                        res.day,
+                        res.part,
+                        answer,
+                        change,
+                        runner::format_duration(res.time)
+                    ),
+                    None => println!(
+                        "Day {} part {}: {} ({})",
+                        res.day, res.part, res.status, change
+                    ),
+                }
+            }
+            previous = results;
+        }
+        thread::sleep(args.interval);
+        changed = watcher.poll();
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match &cli.command {
+        Some(Command::Bench(args)) => bench(args),
+        Some(Command::New(args)) => new(args),
+        Some(Command::Submit(args)) => submit(args),
+        Some(Command::Watch(args)) => watch(args),
+        None => run(&cli),
+    }
+}
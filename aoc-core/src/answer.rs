@@ -0,0 +1,142 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+///
+/// Integers are normalised on construction: anything that fits into `i64` is
+/// `Int`, only larger values end up as `BigInt`. Two answers holding the same
+/// number therefore always compare equal.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Str(String),
+    /// A list of numbers, printed comma separated as the puzzles expect.
+    List(Vec<i64>),
+    /// The answer has to be read off by a human, e.g. a picture drawn by the
+    /// solver. It never compares equal to a stored answer.
+    Visual(String),
+}
+
+impl Answer {
+    /// Parses an answer as typed by a human, e.g. in a file of known answers.
+    pub fn parse(s: &str) -> Answer {
+        let s = s.trim();
+        if let Ok(n) = s.parse::<i128>() {
+            return n.into();
+        }
+        if s.contains(',') {
+            if let Ok(list) = s.split(',').map(|n| n.trim().parse()).collect() {
+                return Answer::List(list);
+            }
+        }
+        Answer::Str(s.to_string())
+    }
+
+    /// The answer as a number, if it is one.
+    pub fn as_number(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(*n as i128),
+            Answer::BigInt(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn is_visual(&self) -> bool {
+        matches!(self, Answer::Visual(_))
+    }
+
+    /// One-line form of the answer, suitable for tables.
+    pub fn summary(&self) -> String {
+        match self {
+            Answer::Visual(_) => "<needs inspection>".to_string(),
+            answer => answer.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => n.fmt(f),
+            Answer::BigInt(n) => n.fmt(f),
+            Answer::Str(s) => s.fmt(f),
+            Answer::List(list) => {
+                let joined: Vec<String> = list.iter().map(|n| n.to_string()).collect();
+                joined.join(",").fmt(f)
+            }
+            Answer::Visual(picture) => picture.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => match i128::try_from(n) {
+                            Ok(n) => Answer::BigInt(n),
+                            Err(_) => Answer::Str(n.to_string()),
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl From<Vec<i64>> for Answer {
+    fn from(list: Vec<i64>) -> Self {
+        Answer::List(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalised_ints() {
+        assert_eq!(Answer::from(42_u8), Answer::Int(42));
+        assert_eq!(Answer::from(42_usize), Answer::from(42_i32));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(u128::MAX), Answer::Str(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Answer::parse(" 161\n"), Answer::Int(161));
+        assert_eq!(
+            Answer::parse("170141183460469231731687303715884105727"),
+            Answer::BigInt(i128::MAX)
+        );
+        assert_eq!(Answer::parse("4,6,3,5"), Answer::List(vec![4, 6, 3, 5]));
+        assert_eq!(Answer::parse("abc,def"), Answer::Str("abc,def".to_string()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::List(vec![6, 1]).to_string(), "6,1");
+        assert_eq!(Answer::BigInt(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(
+            Answer::Visual("#.#".to_string()).summary(),
+            "<needs inspection>"
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::input;
+use crate::runner::{Part, PartResult, Status};
+use crate::Answer;
+
+/// Known answers of one year, stored as TOML with a table per day:
+///
+/// ```toml
+/// [day01]
+/// part1 = 11
+/// part2 = "4,6,3"
+/// ```
+///
+/// Numbers that fit into an `i64` are stored as TOML integers, everything
+/// else as a string that `Answer::parse` turns back into the same answer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), Answer>,
+}
+
+/// Where the answers of a year are kept.
+pub fn answers_path(year: u16) -> PathBuf {
+    input::workspace_dir().join(format!("aoc-{}/answers.toml", year))
+}
+
+fn invalid(path: &Path, msg: impl fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid answers file {}: {}", path.display(), msg),
+    )
+}
+
+impl Answers {
+    /// Loads the answers from `path`. A missing file holds no answers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+        Self::parse(&content).map_err(|msg| invalid(path, msg))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let table: toml::Table = content.parse().map_err(|err| format!("{}", err))?;
+        let mut answers = Self::default();
+        for (day_key, parts) in &table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("expected a table `dayNN`, found `{}`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{}` is not a table", day_key))?;
+            for (part_key, value) in parts {
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse().ok())
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| format!("expected `part1` or `part2`, found `{}`", part_key))?;
+                let answer = match value {
+                    toml::Value::Integer(n) => Answer::Int(*n),
+                    toml::Value::String(s) => Answer::parse(s),
+                    _ => {
+                        return Err(format!(
+                            "{}.{} is not a number or string",
+                            day_key, part_key
+                        ))
+                    }
+                };
+                answers.insert(day, part, answer);
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    /// Stores the answers of all parts that ran successfully. Answers that
+    /// need a human to read them can't be checked and are left out.
+    pub fn record(&mut self, results: &[PartResult]) -> usize {
+        let mut recorded = 0;
+        for res in results {
+            if let (Status::Ok, Some(answer)) = (&res.status, &res.answer) {
+                if !answer.is_visual() {
+                    self.insert(res.day, res.part, answer.clone());
+                    recorded += 1;
+                }
+            }
+        }
+        recorded
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut table = toml::Table::new();
+        for (&(day, part), answer) in &self.answers {
+            let value = match answer {
+                Answer::Int(n) => toml::Value::Integer(*n),
+                answer => toml::Value::String(answer.to_string()),
+            };
+            table
+                .entry(format!("day{:02}", day))
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                .as_table_mut()
+                .expect("day entries are tables")
+                .insert(format!("part{}", part), value);
+        }
+        table.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+/// Compares a result against the known answer. A part without a known answer
+/// is `Missing` whatever its outcome, a part that fails to produce the known
+/// answer is a `Fail`.
+pub fn verify(answers: &Answers, res: &PartResult) -> Verdict {
+    let Some(expected) = answers.get(res.day, res.part) else {
+        return Verdict::Missing;
+    };
+    match &res.answer {
+        Some(answer) if res.status == Status::Ok && answer == expected => Verdict::Pass,
+        _ => Verdict::Fail {
+            expected: expected.clone(),
+        },
+    }
+}
+
+pub fn write_verification(
+    out: &mut impl Write,
+    results: &[PartResult],
+    verdicts: &[Verdict],
+) -> io::Result<()> {
+    writeln!(out, "{:>3}  {:>4}  Result", "Day", "Part")?;
+    for (res, verdict) in results.iter().zip(verdicts) {
+        match verdict {
+            Verdict::Fail { expected } => {
+                let got = match &res.answer {
+                    Some(answer) => answer.summary(),
+                    None => res.status.to_string(),
+                };
+                writeln!(
+                    out,
+                    "{:>3}  {:>4}  {}  expected {}, got {}",
+                    res.day, res.part, verdict, expected, got
+                )?;
+            }
+            verdict => writeln!(out, "{:>3}  {:>4}  {}", res.day, res.part, verdict)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: Part, answer: Option<Answer>, status: Status) -> PartResult {
+        PartResult {
+            year: 2024,
+            day,
+            part,
+            answer,
+            status,
+            time: None,
+            parse_time: None,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, Answer::Int(11));
+        answers.insert(1, Part::Two, Answer::BigInt(i128::MAX));
+        answers.insert(17, Part::One, Answer::List(vec![4, 6, 3]));
+        answers.insert(9, Part::Two, Answer::Str("abc".to_string()));
+
+        let content = answers.to_string();
+        assert!(content.starts_with("[day01]\npart1 = 11\n"));
+        assert_eq!(Answers::parse(&content), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("[day1]\npart3 = 1").is_err());
+        assert!(Answers::parse("[first]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day1]\npart1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, Answer::Int(11));
+        answers.insert(1, Part::Two, Answer::Int(31));
+        answers.insert(2, Part::One, Answer::Int(2));
+
+        let ok = |day, part, n: i64| result(day, part, Some(n.into()), Status::Ok);
+        assert_eq!(verify(&answers, &ok(1, Part::One, 11)), Verdict::Pass);
+        assert_eq!(
+            verify(&answers, &ok(1, Part::Two, 30)),
+            Verdict::Fail {
+                expected: Answer::Int(31)
+            }
+        );
+        assert_eq!(
+            verify(&answers, &result(2, Part::One, None, Status::Unimplemented)),
+            Verdict::Fail {
+                expected: Answer::Int(2)
+            }
+        );
+        assert_eq!(verify(&answers, &ok(2, Part::Two, 4)), Verdict::Missing);
+    }
+}
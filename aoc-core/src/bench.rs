@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::input::InputConfig;
+use crate::runner::{self, format_duration, Part, Status};
+
+#[derive(Copy, Clone, Debug)]
+pub struct BenchConfig {
+    pub runs: usize,
+    pub warmup: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Some(Self {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BenchResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Result<Stats, Status>,
+}
+
+/// Runs each part `config.warmup` times untimed, then `config.runs` times
+/// timed. A part that fails or panics is reported once and not retried.
+pub fn bench_day(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    input: &InputConfig,
+    config: BenchConfig,
+) -> Vec<BenchResult> {
+    let result = |part, outcome| BenchResult {
+        year,
+        day,
+        part,
+        outcome,
+    };
+
+    let (solver, input) = match runner::prepare(input, year, day) {
+        Ok(prepared) => prepared,
+        Err(status) => {
+            return parts
+                .iter()
+                .map(|&part| result(part, Err(status.clone())))
+                .collect();
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let mut samples = Vec::with_capacity(config.runs);
+            for i in 0..config.warmup + config.runs {
+                let (_, status, time) = runner::run_part(&*solver, day, part, &input);
+                match (status, time) {
+                    (Status::Ok, Some(time)) if i >= config.warmup => samples.push(time),
+                    (Status::Ok, _) => {}
+                    (status, _) => return result(part, Err(status)),
+                }
+            }
+            let stats = Stats::from_samples(&samples)
+                .ok_or_else(|| Status::Failed("no timed runs".to_string()));
+            result(part, stats)
+        })
+        .collect()
+}
+
+pub fn print_report(results: &[BenchResult]) {
+    println!(
+        "{:>3}  {:>4}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Part", "Runs", "Min", "Median", "Mean", "Stddev"
+    );
+    for res in results {
+        match &res.outcome {
+            Ok(stats) => println!(
+                "{:>3}  {:>4}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+                res.day,
+                res.part,
+                stats.runs,
+                format_duration(Some(stats.min)),
+                format_duration(Some(stats.median)),
+                format_duration(Some(stats.mean)),
+                format_duration(Some(stats.stddev)),
+            ),
+            Err(status) => println!("{:>3}  {:>4}  {}", res.day, res.part, status),
+        }
+    }
+}
+
+type Baseline = HashMap<(u16, u8, Part), Stats>;
+
+const HEADER: &str = "year,day,part,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// Saves the successful results as CSV, one line per year, day and part.
+pub fn save(path: &Path, results: &[BenchResult]) -> io::Result<()> {
+    let mut out = String::from(HEADER);
+    out.push('\n');
+    for res in results {
+        if let Ok(stats) = &res.outcome {
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                res.year,
+                res.day,
+                res.part,
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos(),
+            ));
+        }
+    }
+    fs::write(path, out)
+}
+
+pub fn load(path: &Path) -> io::Result<Baseline> {
+    let invalid = |line: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid benchmark line in {}: {}", path.display(), line),
+        )
+    };
+
+    let mut baseline = Baseline::new();
+    for line in fs::read_to_string(path)?.lines().skip(1) {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [year, day, part, runs, min, median, mean, stddev] = fields[..] else {
+            return Err(invalid(line));
+        };
+        let nanos = |s: &str| {
+            s.parse()
+                .map(Duration::from_nanos)
+                .map_err(|_| invalid(line))
+        };
+        let year = year.parse().map_err(|_| invalid(line))?;
+        let day = day.parse().map_err(|_| invalid(line))?;
+        let part = part
+            .parse()
+            .ok()
+            .and_then(Part::from_number)
+            .ok_or_else(|| invalid(line))?;
+        let stats = Stats {
+            runs: runs.parse().map_err(|_| invalid(line))?,
+            min: nanos(min)?,
+            median: nanos(median)?,
+            mean: nanos(mean)?,
+            stddev: nanos(stddev)?,
+        };
+        baseline.insert((year, day, part), stats);
+    }
+    Ok(baseline)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: Part,
+    pub before: Duration,
+    pub after: Duration,
+    /// Relative change of the median in percent, positive means slower.
+    pub change: f64,
+    pub regression: bool,
+}
+
+/// Compares medians against an earlier run. Parts missing from either side
+/// are skipped.
+pub fn compare(baseline: &Baseline, results: &[BenchResult], threshold: f64) -> Vec<Comparison> {
+    results
+        .iter()
+        .filter_map(|res| {
+            let after = res.outcome.as_ref().ok()?.median;
+            let before = baseline.get(&(res.year, res.day, res.part))?.median;
+            let change = if before.is_zero() {
+                0.0
+            } else {
+                (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+            };
+            Some(Comparison {
+                day: res.day,
+                part: res.part,
+                before,
+                after,
+                change,
+                regression: change > threshold,
+            })
+        })
+        .collect()
+}
+
+pub fn print_comparison(comparisons: &[Comparison]) {
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  {:>8}",
+        "Day", "Part", "Before", "After", "Change"
+    );
+    for cmp in comparisons {
+        println!(
+            "{:>3}  {:>4}  {:>10}  {:>10}  {:>+7.1}%{}",
+            cmp.day,
+            cmp.part,
+            format_duration(Some(cmp.before)),
+            format_duration(Some(cmp.after)),
+            cmp.change,
+            if cmp.regression { "  REGRESSION" } else { "" }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(8)]).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let stats = |median| Stats {
+            runs: 1,
+            min: median,
+            median,
+            mean: median,
+            stddev: Duration::ZERO,
+        };
+        let baseline = Baseline::from([
+            ((2024, 1, Part::One), stats(ms(100))),
+            ((2024, 1, Part::Two), stats(ms(100))),
+        ]);
+        let results = [
+            BenchResult {
+                year: 2024,
+                day: 1,
+                part: Part::One,
+                outcome: Ok(stats(ms(105))),
+            },
+            BenchResult {
+                year: 2024,
+                day: 1,
+                part: Part::Two,
+                outcome: Ok(stats(ms(150))),
+            },
+            BenchResult {
+                year: 2024,
+                day: 2,
+                part: Part::One,
+                outcome: Ok(stats(ms(150))),
+            },
+        ];
+        let comparisons = compare(&baseline, &results, 10.0);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regression);
+        assert!(comparisons[1].regression);
+        assert_eq!(comparisons[1].change.round(), 50.0);
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt;
+
+use crate::runner::Part;
+use crate::utils;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// 1-based position inside the puzzle input.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Position of `fragment` inside `input`, if `fragment` is a slice of it.
+    pub fn of(input: &str, fragment: &str) -> Option<Position> {
+        let offset = utils::offset_of(input, fragment)?;
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Some(Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        })
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// The input can't be read.
+    Input(String),
+    /// The input doesn't look like the puzzle says it should.
+    Parse {
+        position: Option<Position>,
+        expected: String,
+        found: String,
+    },
+    /// The input parsed fine, but the solver found no answer for it.
+    Solve(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    fn new(kind: ErrorKind) -> Self {
+        Self {
+            day: None,
+            part: None,
+            kind,
+        }
+    }
+
+    pub fn input(msg: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Input(msg.into()))
+    }
+
+    pub fn solve(msg: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Solve(msg.into()))
+    }
+
+    /// A parse error without a known position, see `within` to add one.
+    pub fn parse(expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse {
+            position: None,
+            expected: expected.into(),
+            found: found.into(),
+        })
+    }
+
+    /// A parse error pointing at `token`, which has to be a slice of `context`.
+    pub fn parse_at(context: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse {
+            position: Position::of(context, token),
+            expected: expected.into(),
+            found: token.to_string(),
+        })
+    }
+
+    /// Makes the position of a parse error, which is relative to `fragment`,
+    /// relative to `input` instead. Errors without a position point at the
+    /// start of `fragment`. Does nothing if `fragment` isn't a slice of `input`.
+    pub fn within(mut self, input: &str, fragment: &str) -> Self {
+        if let ErrorKind::Parse { position, .. } = &mut self.kind {
+            if let Some(start) = Position::of(input, fragment) {
+                *position = Some(match position {
+                    Some(pos) if pos.line == 1 => Position {
+                        line: start.line,
+                        column: start.column + pos.column - 1,
+                    },
+                    Some(pos) => Position {
+                        line: start.line + pos.line - 1,
+                        column: pos.column,
+                    },
+                    None => start,
+                });
+            }
+        }
+        self
+    }
+
+    pub fn in_part(self, day: u8, part: Part) -> Self {
+        Self {
+            day: Some(day),
+            part: Some(part),
+            ..self
+        }
+    }
+
+    pub fn in_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Input(msg) | ErrorKind::Solve(msg) => write!(f, "{}", msg),
+            ErrorKind::Parse {
+                position,
+                expected,
+                found,
+            } => {
+                if let Some(position) = position {
+                    write!(f, "{}: ", position)?;
+                }
+                write!(f, "expected {}, found {:?}", expected, found)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {} part {}: ", day, part)?,
+            (Some(day), None) => write!(f, "day {}: ", day)?,
+            _ => {}
+        }
+        self.kind.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let input = "ab\ncde\nf";
+        assert_eq!(
+            Position::of(input, &input[4..6]),
+            Some(Position { line: 2, column: 2 })
+        );
+        assert_eq!(
+            Position::of(input, &input[7..]),
+            Some(Position { line: 3, column: 1 })
+        );
+        assert_eq!(Position::of(input, "cde"), None);
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "1 2\n3 x 4\n5";
+        let line = input.lines().nth(1).unwrap();
+        let err = Error::parse_at(line, &line[2..3], "integer").within(input, line);
+        assert_eq!(
+            err.in_part(1, Part::Two).to_string(),
+            "day 1 part 2: line 2, column 3: expected integer, found \"x\""
+        );
+
+        let block = &input[4..];
+        let err = Error::parse("integer", "x").within(input, block);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected integer, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::runner::Part;
+use crate::{Answer, Error, Params, Result};
+
+/// Separates the header of an example file from its input.
+pub const HEADER_END: &str = "---";
+
+/// An example from a puzzle text, as kept in an example file. The input can
+/// be preceded by a header of `key: value` lines, ended by a `---` line:
+///
+/// ```text
+/// part1: 11
+/// part2: 31
+/// ---
+/// 3   4
+/// 4   3
+/// ```
+///
+/// Keys other than `part1` and `part2` are puzzle parameters, e.g.
+/// `width: 11`, see `Params`. A file without a `---` line is all input.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Example {
+    pub header: BTreeMap<String, String>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn load(path: &Path) -> Result<Example> {
+        let content = fs::read_to_string(path).map_err(|err| {
+            Error::input(format!(
+                "Error reading example file {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        Example::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Example> {
+        let Some((header, input)) = split_header(content) else {
+            return Ok(Example {
+                header: BTreeMap::new(),
+                input: content.to_string(),
+            });
+        };
+        let mut entries = BTreeMap::new();
+        for line in header.lines().filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| Error::parse_at(line, line, "`key: value`").within(content, line))?;
+            entries.insert(key.trim().to_string(), value.trim().to_string());
+        }
+        Ok(Example {
+            header: entries,
+            input: input.to_string(),
+        })
+    }
+
+    /// The answer the puzzle text gives for `part`, from the `partN` key.
+    pub fn expected(&self, part: Part) -> Option<Answer> {
+        self.header
+            .get(&format!("part{}", part))
+            .map(|answer| Answer::parse(answer))
+    }
+
+    /// The puzzle parameters of the example.
+    pub fn params(&self) -> Params {
+        self.header
+            .iter()
+            .filter(|(key, _)| !matches!(key.as_str(), "part1" | "part2"))
+            .collect()
+    }
+}
+
+fn split_header(content: &str) -> Option<(&str, &str)> {
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        if line.trim_end() == HEADER_END {
+            return Some((&content[..offset], &content[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse("part1: 11\npart2: 4,6,3\n---\n3   4\n4   3\n").unwrap();
+        assert_eq!(example.input, "3   4\n4   3\n");
+        assert_eq!(example.expected(Part::One), Some(Answer::Int(11)));
+        assert_eq!(
+            example.expected(Part::Two),
+            Some(Answer::List(vec![4, 6, 3]))
+        );
+
+        assert!(example.params().is_empty());
+
+        let example = Example::parse("part1: 12\nwidth: 11\n---\np=0,4 v=3,-3\n").unwrap();
+        assert_eq!(example.params(), [("width", "11")].into_iter().collect());
+
+        let example = Example::parse("3   4\n4   3").unwrap();
+        assert_eq!(example.input, "3   4\n4   3");
+        assert_eq!(example.expected(Part::One), None);
+    }
+
+    #[test]
+    fn test_malformed_header() {
+        let err = Example::parse("part1: 11\npart2 31\n---\n3   4").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected `key: value`, found \"part2 31\""
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Error, Result};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Minimum time between two requests to the puzzle server, also across runs.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Serialises downloads, so that a day asked for twice at once is still
+/// fetched only once.
+static FETCH_LOCK: Mutex<()> = Mutex::new(());
+
+/// Downloads puzzle inputs and keeps them in a per-user cache. An input is
+/// only ever downloaded once, later runs read it from the cache.
+///
+/// Settings come from `config.toml` in the user's config directory, e.g.
+/// `~/.config/aoc/config.toml` on Linux, and can be overridden by
+/// environment variables:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."  # or $AOC_SESSION
+/// base_url = "https://adventofcode.com"  # or $AOC_BASE_URL
+/// cache_dir = "/home/me/.cache/aoc"  # or $AOC_CACHE_DIR
+/// contact = "me@example.com"  # added to the User-Agent
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FetchConfig {
+    pub base_url: String,
+    /// Value of the `session` cookie of a logged in user. Without it nothing
+    /// is downloaded, but cached inputs are still found.
+    pub session: Option<String>,
+    pub contact: Option<String>,
+    pub cache_dir: PathBuf,
+    pub interval: Duration,
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("aoc/config.toml"))
+}
+
+fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir().unwrap_or_else(env::temp_dir).join("aoc")
+}
+
+impl FetchConfig {
+    /// Reads the settings from the config file, if there is one, and the
+    /// environment.
+    pub fn load() -> io::Result<Self> {
+        let mut table = toml::Table::new();
+        if let Some(path) = config_path() {
+            match fs::read_to_string(&path) {
+                Ok(content) => {
+                    table = content.parse().map_err(|err| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid config file {}: {}", path.display(), err),
+                        )
+                    })?;
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let setting = |var: &str, key: &str| {
+            env::var(var)
+                .ok()
+                .or_else(|| table.get(key)?.as_str().map(str::to_string))
+                .filter(|value| !value.is_empty())
+        };
+
+        Ok(Self {
+            base_url: setting(BASE_URL_VAR, "base_url")
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            session: setting(SESSION_VAR, "session"),
+            contact: table
+                .get("contact")
+                .and_then(|contact| contact.as_str())
+                .map(str::to_string),
+            cache_dir: setting(CACHE_DIR_VAR, "cache_dir")
+                .map_or_else(default_cache_dir, PathBuf::from),
+            interval: DEFAULT_INTERVAL,
+        })
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn user_agent(&self) -> String {
+        let agent = concat!("aoc-core/", env!("CARGO_PKG_VERSION"));
+        match &self.contact {
+            Some(contact) => format!("{} ({})", agent, contact),
+            None => agent.to_string(),
+        }
+    }
+
+    /// The input of a day, from the cache or else from the server.
+    pub fn get(&self, year: u16, day: u8) -> Result<String> {
+        let _lock = FETCH_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+
+        let path = self.cache_path(year, day);
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+        let Some(session) = &self.session else {
+            return Err(Error::input(format!(
+                "no input for day {} and {}",
+                day,
+                missing_session("download it")
+            )));
+        };
+
+        let url = self.day_url(year, day, "input");
+        let response = self.request(ureq::get, &url, session).call();
+        let input = read_response(&url, response)?;
+
+        store(&path, &input).map_err(|err| {
+            Error::input(format!(
+                "Error caching input in {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        Ok(input)
+    }
+
+    /// `page` of the puzzle of a day on the server.
+    pub(crate) fn day_url(&self, year: u16, day: u8, page: &str) -> String {
+        format!(
+            "{}/{}/day/{}/{}",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day,
+            page
+        )
+    }
+
+    /// A request to the server on behalf of the user, sent after throttling.
+    pub(crate) fn request(
+        &self,
+        method: fn(&str) -> ureq::Request,
+        url: &str,
+        session: &str,
+    ) -> ureq::Request {
+        self.throttle();
+        method(url)
+            .set("User-Agent", &self.user_agent())
+            .set("Cookie", &format!("session={}", session))
+            .timeout(Duration::from_secs(30))
+    }
+
+    /// Waits until `interval` has passed since the last request, which is
+    /// remembered in the cache directory so that it holds across runs.
+    ///
+    /// The time is written into the file, its modification time may lag
+    /// behind the clock by a few milliseconds.
+    fn throttle(&self) {
+        let stamp = self.cache_dir.join("last_request");
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+        let elapsed = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|nanos| nanos.trim().parse().ok())
+            .and_then(|nanos| now().checked_sub(Duration::from_nanos(nanos)));
+        if let Some(wait) = elapsed.and_then(|elapsed| self.interval.checked_sub(elapsed)) {
+            thread::sleep(wait);
+        }
+        // Without a cache directory the download can't be stored either,
+        // which is reported then.
+        let _ = fs::create_dir_all(&self.cache_dir)
+            .and_then(|_| fs::write(&stamp, now().as_nanos().to_string()));
+    }
+}
+
+/// The error for a missing session token, which is needed to `action`.
+pub(crate) fn missing_session(action: &str) -> String {
+    format!(
+        "no session token to {}, set ${} or `session` in {}",
+        action,
+        SESSION_VAR,
+        config_path().map_or("the config file".to_string(), |p| p.display().to_string())
+    )
+}
+
+/// The body of a successful response.
+pub(crate) fn read_response(
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+) -> Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| Error::input(format!("Error reading response from {}: {}", url, err))),
+        Err(ureq::Error::Status(code, _)) => Err(Error::input(format!(
+            "Error requesting {}: the server answered {}",
+            url, code
+        ))),
+        Err(err) => Err(Error::input(format!("Error requesting {}: {}", url, err))),
+    }
+}
+
+/// Writes `content` to `path` so that no partial file is ever seen there.
+fn store(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, content)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::StubServer;
+    use std::time::Instant;
+
+    /// Serves `body` for every input but day 9, which is "not found".
+    fn stub_server(body: &'static str) -> StubServer {
+        StubServer::start(move |request| {
+            if request.head.contains("/day/9/") {
+                (404, String::new())
+            } else {
+                (200, body.to_string())
+            }
+        })
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let server = stub_server("1 2\n3 4\n");
+        let config = server.config("fetch-once");
+
+        assert_eq!(config.get(2024, 1), Ok("1 2\n3 4\n".to_string()));
+        assert_eq!(config.get(2024, 1), Ok("1 2\n3 4\n".to_string()));
+        let no_session = FetchConfig {
+            session: None,
+            ..config.clone()
+        };
+        assert_eq!(no_session.get(2024, 1), Ok("1 2\n3 4\n".to_string()));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let head = &requests[0].head;
+        assert!(head.starts_with("get /2024/day/1/input "), "{}", head);
+        assert!(head.contains("\r\ncookie: session=c0ffee\r\n"), "{}", head);
+        let user_agent = config.user_agent();
+        assert!(user_agent.ends_with(" (me@example.com)"));
+        assert!(
+            head.contains(&format!("\r\nuser-agent: {}\r\n", user_agent)),
+            "{}",
+            head
+        );
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let server = stub_server("1 2\n");
+        let config = server.config("fetch-errors");
+
+        let err = config.get(2024, 9).unwrap_err();
+        assert!(
+            err.to_string().ends_with("the server answered 404"),
+            "{}",
+            err
+        );
+        assert!(!config.cache_path(2024, 9).exists());
+
+        let no_session = FetchConfig {
+            session: None,
+            ..config.clone()
+        };
+        assert!(no_session.get(2024, 2).is_err());
+        assert_eq!(server.requests().len(), 1);
+        let _ = fs::remove_dir_all(&config.cache_dir);
+    }
+
+    #[test]
+    fn test_throttle() {
+        let server = stub_server("1 2\n");
+        let config = FetchConfig {
+            interval: Duration::from_millis(300),
+            ..server.config("fetch-throttle")
+        };
+
+        let start = Instant::now();
+        config.get(2024, 1).unwrap();
+        config.get(2024, 2).unwrap();
+        assert!(start.elapsed() >= config.interval);
+        assert_eq!(server.requests().len(), 2);
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::example::Example;
+use crate::fetch::FetchConfig;
+use crate::{Error, Params, Result};
+
+/// Environment variable naming the data directory, see `DataDir`.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Root of the workspace the binary was built from. Paths inside the
+/// workspace are resolved against it, so the binary works from anywhere.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-core lives inside the workspace")
+}
+
+/// Where puzzle inputs and examples are kept.
+///
+/// By default each year keeps them next to its solvers, in `aoc-YYYY/data`.
+/// A data directory set explicitly has a subdirectory per year instead, e.g.
+/// `DIR/2024/day01.txt`, so that inputs can live outside the repository.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DataDir {
+    root: Option<PathBuf>,
+}
+
+impl DataDir {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root: Some(root) }
+    }
+
+    /// The data directory named by `$AOC_DATA_DIR`, if set, or the default.
+    pub fn from_env() -> Self {
+        Self {
+            root: env::var_os(DATA_DIR_VAR).map(PathBuf::from),
+        }
+    }
+
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        match &self.root {
+            Some(root) => root.join(year.to_string()),
+            None => workspace_dir().join(format!("aoc-{}/data", year)),
+        }
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("day{:02}.txt", day))
+    }
+
+    /// Path of the `n`th example of a day, numbered from 1.
+    pub fn example_path(&self, year: u16, day: u8, n: u32) -> PathBuf {
+        self.year_dir(year)
+            .join(format!("day{:02}_example{}.txt", day, n))
+    }
+}
+
+/// Which input a run is given.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum InputSource {
+    /// The day's puzzle input.
+    #[default]
+    Puzzle,
+    /// A numbered example of the day, see `Example` for the file format.
+    Example(u32),
+    /// A file given by the user, taken as it is.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Whether the source is the same whatever the day.
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, InputSource::File(_) | InputSource::Stdin)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InputConfig {
+    pub data_dir: DataDir,
+    pub source: InputSource,
+    /// Where to get puzzle inputs that aren't in the data directory. An empty
+    /// input file, as left by `new`, counts as missing.
+    pub fetch: Option<FetchConfig>,
+    /// Puzzle parameters, which override those of an example.
+    pub params: Params,
+}
+
+impl InputConfig {
+    pub fn read(&self, year: u16, day: u8) -> Result<String> {
+        self.read_with_params(year, day).map(|(input, _)| input)
+    }
+
+    /// The input of a day together with the puzzle parameters to solve it
+    /// with.
+    pub fn read_with_params(&self, year: u16, day: u8) -> Result<(String, Params)> {
+        let mut params = Params::default();
+        let input = match &self.source {
+            InputSource::Puzzle => {
+                let path = self.data_dir.input_path(year, day);
+                let is_missing = fs::metadata(&path).map_or(true, |meta| meta.len() == 0);
+                match &self.fetch {
+                    Some(fetch) if is_missing => fetch.get(year, day),
+                    _ => read_file(&path),
+                }
+            }
+            InputSource::Example(n) => Example::load(&self.data_dir.example_path(year, day, *n))
+                .map(|example| {
+                    params = example.params();
+                    example.input
+                }),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|err| Error::input(format!("Error reading stdin: {}", err)))
+            }
+        };
+        params.extend(&self.params);
+        input
+            .map(|input| (input, params))
+            .map_err(|err| err.in_day(day))
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| {
+        Error::input(format!(
+            "Error reading input file {}: {}",
+            path.display(),
+            err
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let data_dir = DataDir::new(PathBuf::from("/inputs"));
+        assert_eq!(
+            data_dir.input_path(2024, 7),
+            Path::new("/inputs/2024/day07.txt")
+        );
+        assert_eq!(
+            data_dir.example_path(2023, 12, 2),
+            Path::new("/inputs/2023/day12_example2.txt")
+        );
+        assert_eq!(
+            DataDir::default().input_path(2024, 7),
+            workspace_dir().join("aoc-2024/data/day07.txt")
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2024")).unwrap();
+        fs::write(dir.join("2024/day03.txt"), "puzzle\n").unwrap();
+        fs::write(
+            dir.join("2024/day03_example1.txt"),
+            "part1: 1\nsize: 7\nsteps: 12\n---\nexample\n",
+        )
+        .unwrap();
+
+        let config = |source| InputConfig {
+            data_dir: DataDir::new(dir.clone()),
+            source,
+            fetch: None,
+            params: [("steps", "3")].into_iter().collect(),
+        };
+        assert_eq!(
+            config(InputSource::Puzzle).read(2024, 3),
+            Ok("puzzle\n".into())
+        );
+        assert_eq!(
+            config(InputSource::Example(1)).read(2024, 3),
+            Ok("example\n".into())
+        );
+        assert_eq!(
+            config(InputSource::Example(1)).read_with_params(2024, 3),
+            Ok((
+                "example\n".into(),
+                [("size", "7"), ("steps", "3")].into_iter().collect()
+            ))
+        );
+        let err = config(InputSource::Example(2)).read(2024, 3).unwrap_err();
+        assert_eq!(err.day, Some(3));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
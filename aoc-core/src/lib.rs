@@ -0,0 +1,61 @@
+mod answer;
+mod error;
+
+pub use answer::Answer;
+pub use error::{Error, ErrorKind, Position, Result};
+pub use params::Params;
+pub use registry::{get_solver, Registration};
+
+/// Solvers are shared between the threads that run them, see `runner::run_days`.
+pub trait Solution: Send + Sync {
+    fn part1(&self, input: &str) -> Result<Answer>;
+    fn part2(&self, input: &str) -> Result<Answer>;
+
+    /// Parses the input without solving anything, so that the runner can time
+    /// parsing on its own. Solvers that parse inside the parts return `None`.
+    fn parse(&self, _input: &str) -> Option<Result<()>> {
+        None
+    }
+
+    /// The solver set up with runtime parameters, see `Params`. Solvers
+    /// without parameters return `None`.
+    fn with_params(&self, _params: &Params) -> Option<Result<Box<dyn Solution>>> {
+        None
+    }
+}
+
+impl<S: Solution + ?Sized> Solution for &S {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        (**self).part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        (**self).part2(input)
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        (**self).parse(input)
+    }
+
+    fn with_params(&self, params: &Params) -> Option<Result<Box<dyn Solution>>> {
+        (**self).with_params(params)
+    }
+}
+
+pub mod utils;
+
+pub mod answers;
+pub mod bench;
+pub mod example;
+pub mod fetch;
+pub mod input;
+pub mod params;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+#[cfg(test)]
+mod stub_server;
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, Result};
+
+/// Puzzle parameters given at runtime, such as the size of a grid that is
+/// smaller in the examples than in the real puzzle.
+///
+/// Solvers with parameters keep them in a struct of their own, with the
+/// values of the real puzzle as defaults, and override those from these in
+/// `Solution::with_params`. Values come from the header of an example file
+/// and from `--param key=value`, which wins.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    /// Adds the values of `other`, replacing those for the same keys.
+    pub fn extend(&mut self, other: &Params) {
+        self.0
+            .extend(other.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    /// Parses a `key=value` argument.
+    pub fn parse_arg(arg: &str) -> Result<(String, String)> {
+        match arg.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(Error::parse("`key=value`", arg)),
+        }
+    }
+
+    /// Replaces `value` by the one given for `key`, if any.
+    pub fn apply<T: FromStr>(&self, key: &str, value: &mut T) -> Result<()> {
+        if let Some(given) = self.0.get(key) {
+            *value = given
+                .parse()
+                .map_err(|_| Error::input(format!("invalid value {:?} for `{}`", given, key)))?;
+        }
+        Ok(())
+    }
+
+    /// Fails on keys other than `known`, which are most likely typos.
+    pub fn check_keys(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            Some(key) => Err(Error::input(format!(
+                "unknown parameter `{}`, expected one of {}",
+                key,
+                known.join(", ")
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let pairs: Vec<String> = self.0.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        write!(f, "{}", pairs.join(" "))
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Params(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut params: Params = [("width", "11"), ("height", "x")].into_iter().collect();
+        let mut width = 101;
+        params.apply("width", &mut width).unwrap();
+        assert_eq!(width, 11);
+        let mut steps = 100;
+        params.apply("steps", &mut steps).unwrap();
+        assert_eq!(steps, 100);
+        let mut height = 103;
+        assert!(params.apply("height", &mut height).is_err());
+
+        assert!(params.check_keys(&["width", "height"]).is_ok());
+        params.set("widht", "7");
+        let err = params.check_keys(&["width", "height"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown parameter `widht`, expected one of width, height"
+        );
+    }
+
+    #[test]
+    fn test_parse_arg() {
+        assert_eq!(
+            Params::parse_arg("width=11"),
+            Ok(("width".to_string(), "11".to_string()))
+        );
+        assert!(Params::parse_arg("width").is_err());
+        assert!(Params::parse_arg("=11").is_err());
+    }
+}
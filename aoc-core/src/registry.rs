@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::Solution;
+
+#[doc(hidden)]
+pub use inventory;
+
+/// A solver together with the puzzle it solves. Created by `register!`.
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    pub solver: &'static dyn Solution,
+}
+
+inventory::collect!(Registration);
+
+/// Registers a solver for a day, e.g. `register!(2024, 1, Day01);` next to the
+/// `Day01` struct. Registered days are picked up by the runner, the benchmarks
+/// and `--all` without being listed anywhere else.
+#[macro_export]
+macro_rules! register {
+    ($year:expr, $day:expr, $solver:expr) => {
+        $crate::registry::inventory::submit! {
+            $crate::registry::Registration {
+                year: $year,
+                day: $day,
+                solver: &$solver,
+            }
+        }
+    };
+}
+
+/// All registered solvers, ordered by year and day.
+pub fn registrations() -> Vec<&'static Registration> {
+    let mut registrations: Vec<_> = inventory::iter::<Registration>.into_iter().collect();
+    registrations.sort_by_key(|reg| (reg.year, reg.day));
+    registrations
+}
+
+pub fn get_solver(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .find(|reg| reg.year == year && reg.day == day)
+        .map(|reg| reg.solver)
+}
+
+/// Consistency problems of the solvers registered for `year`, for use in the
+/// tests of a year crate: `dayNN.rs` files in `crate_dir/src` without a
+/// registered solver, and days registered more than once.
+pub fn problems(year: u16, crate_dir: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut seen = HashSet::new();
+    for reg in registrations().iter().filter(|reg| reg.year == year) {
+        if !seen.insert(reg.day) {
+            problems.push(format!("day {} registered twice", reg.day));
+        }
+    }
+
+    let src = Path::new(crate_dir).join("src");
+    let mut files: Vec<String> = match fs::read_dir(&src) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect(),
+        Err(err) => return vec![format!("can't read {}: {}", src.display(), err)],
+    };
+    files.sort();
+    for name in files {
+        let day = name
+            .strip_prefix("day")
+            .and_then(|name| name.strip_suffix(".rs"))
+            .and_then(|day| day.parse().ok());
+        if let Some(day) = day {
+            if !seen.contains(&day) {
+                problems.push(format!("{} has no registered solver", name));
+            }
+        }
+    }
+    problems
+}
@@ -0,0 +1,248 @@
+//! Machine-readable reports of a run, for tools that consume the results.
+//!
+//! Both formats hold one record per part with these fields, in this order:
+//!
+//! | field         | type            | meaning                                              |
+//! |---------------|-----------------|------------------------------------------------------|
+//! | `year`        | integer         | puzzle year                                          |
+//! | `day`         | integer         | puzzle day, 1 to 25                                  |
+//! | `part`        | integer         | 1 or 2                                               |
+//! | `status`      | string          | `ok`, `error`, `timeout` or `unimplemented`          |
+//! | `answer_type` | string or null  | `int`, `bigint`, `str`, `list` or `visual`           |
+//! | `answer`      | see below       | the answer, null unless the status is `ok`           |
+//! | `duration_ns` | integer or null | time of the part in nanoseconds                      |
+//! | `parse_ns`    | integer or null | time of the solver's separate parsing step, if any   |
+//! | `error`       | string or null  | what went wrong, for the status `error`              |
+//!
+//! In JSON, `int` answers are numbers, `list` answers arrays of numbers and
+//! all others strings. `bigint` answers don't fit into 64 bits and are
+//! strings so that no precision is lost. The records are wrapped in an object
+//! with the version of the schema, which changes only when fields are removed
+//! or change their meaning:
+//!
+//! ```json
+//! {"version": 1, "results": [
+//!   {"year": 2024, "day": 3, "part": 1, "status": "ok", "answer_type": "int", "answer": 161, ...}
+//! ]}
+//! ```
+//!
+//! CSV has a header row with the field names. Answers are in their usual
+//! text form, e.g. `4,6,3` for lists, and null is an empty field.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use crate::runner::{PartResult, Status};
+use crate::Answer;
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub const FIELDS: [&str; 9] = [
+    "year",
+    "day",
+    "part",
+    "status",
+    "answer_type",
+    "answer",
+    "duration_ns",
+    "parse_ns",
+    "error",
+];
+
+pub fn status_name(status: &Status) -> &'static str {
+    match status {
+        Status::Ok => "ok",
+        Status::Unimplemented => "unimplemented",
+        Status::Timeout => "timeout",
+        Status::Error(_) | Status::Failed(_) => "error",
+    }
+}
+
+pub fn answer_type(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Int(_) => "int",
+        Answer::BigInt(_) => "bigint",
+        Answer::Str(_) => "str",
+        Answer::List(_) => "list",
+        Answer::Visual(_) => "visual",
+    }
+}
+
+fn error_message(status: &Status) -> Option<String> {
+    match status {
+        Status::Error(err) => Some(err.kind.to_string()),
+        Status::Failed(msg) => Some(format!("panicked: {}", msg)),
+        _ => None,
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(n) => n.to_string(),
+        Answer::List(list) => {
+            let items: Vec<String> = list.iter().map(|n| n.to_string()).collect();
+            format!("[{}]", items.join(", "))
+        }
+        answer => json_string(&answer.to_string()),
+    }
+}
+
+fn or_null(value: Option<String>) -> String {
+    value.unwrap_or_else(|| "null".to_string())
+}
+
+fn json_record(res: &PartResult) -> String {
+    let values = [
+        res.year.to_string(),
+        res.day.to_string(),
+        res.part.to_string(),
+        json_string(status_name(&res.status)),
+        or_null(res.answer.as_ref().map(|a| json_string(answer_type(a)))),
+        or_null(res.answer.as_ref().map(json_answer)),
+        or_null(res.time.map(|time| time.as_nanos().to_string())),
+        or_null(res.parse_time.map(|time| time.as_nanos().to_string())),
+        or_null(error_message(&res.status).map(|msg| json_string(&msg))),
+    ];
+    let fields: Vec<String> = FIELDS
+        .iter()
+        .zip(values)
+        .map(|(field, value)| format!("\"{}\": {}", field, value))
+        .collect();
+    format!("{{{}}}", fields.join(", "))
+}
+
+pub fn write_json(out: &mut impl Write, results: &[PartResult]) -> io::Result<()> {
+    writeln!(out, "{{\"version\": {}, \"results\": [", SCHEMA_VERSION)?;
+    for (i, res) in results.iter().enumerate() {
+        let sep = if i + 1 < results.len() { "," } else { "" };
+        writeln!(out, "  {}{}", json_record(res), sep)?;
+    }
+    writeln!(out, "]}}")
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn write_csv(out: &mut impl Write, results: &[PartResult]) -> io::Result<()> {
+    writeln!(out, "{}", FIELDS.join(","))?;
+    for res in results {
+        let values = [
+            res.year.to_string(),
+            res.day.to_string(),
+            res.part.to_string(),
+            status_name(&res.status).to_string(),
+            res.answer
+                .as_ref()
+                .map_or(String::new(), |a| answer_type(a).to_string()),
+            res.answer.as_ref().map_or(String::new(), |a| a.to_string()),
+            res.time
+                .map_or(String::new(), |time| time.as_nanos().to_string()),
+            res.parse_time
+                .map_or(String::new(), |time| time.as_nanos().to_string()),
+            error_message(&res.status).unwrap_or_default(),
+        ];
+        let fields: Vec<String> = values.iter().map(|value| csv_field(value)).collect();
+        writeln!(out, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Part;
+    use crate::Error;
+    use std::time::Duration;
+
+    fn results() -> Vec<PartResult> {
+        let result = |day, part, answer, status, time| PartResult {
+            year: 2024,
+            day,
+            part,
+            answer,
+            status,
+            time,
+            parse_time: None,
+        };
+        vec![
+            result(
+                3,
+                Part::One,
+                Some(161.into()),
+                Status::Ok,
+                Some(Duration::from_micros(12)),
+            ),
+            result(
+                17,
+                Part::One,
+                Some(Answer::List(vec![4, 6, 3])),
+                Status::Ok,
+                Some(Duration::from_nanos(7)),
+            ),
+            result(
+                1,
+                Part::Two,
+                None,
+                Status::Error(Error::parse("integer", "x\"y").in_part(1, Part::Two)),
+                None,
+            ),
+            result(6, Part::Two, None, Status::Timeout, None),
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        let mut out = Vec::new();
+        write_json(&mut out, &results()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"{"version": 1, "results": [
+  {"year": 2024, "day": 3, "part": 1, "status": "ok", "answer_type": "int", "answer": 161, "duration_ns": 12000, "parse_ns": null, "error": null},
+  {"year": 2024, "day": 17, "part": 1, "status": "ok", "answer_type": "list", "answer": [4, 6, 3], "duration_ns": 7, "parse_ns": null, "error": null},
+  {"year": 2024, "day": 1, "part": 2, "status": "error", "answer_type": null, "answer": null, "duration_ns": null, "parse_ns": null, "error": "expected integer, found \"x\\\"y\""},
+  {"year": 2024, "day": 6, "part": 2, "status": "timeout", "answer_type": null, "answer": null, "duration_ns": null, "parse_ns": null, "error": null}
+]}
+"#
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &results()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"year,day,part,status,answer_type,answer,duration_ns,parse_ns,error
+2024,3,1,ok,int,161,12000,,
+2024,17,1,ok,list,"4,6,3",7,,
+2024,1,2,error,,,,,"expected integer, found ""x\""y"""
+2024,6,2,timeout,,,,,
+"#
+        );
+    }
+}
@@ -0,0 +1,433 @@
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::input::InputConfig;
+use crate::{get_solver, registry, Answer, Error, Result, Solution};
+
+pub const MAX_DAY: u8 = 25;
+
+/// Stack size of the threads solvers run on. Some solvers recurse deeply,
+/// more than the default of spawned threads allows.
+const SOLVER_STACK_SIZE: usize = 64 << 20;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn solve(&self, solver: &dyn Solution, input: &str) -> Result<Answer> {
+        match self {
+            Part::One => solver.part1(input),
+            Part::Two => solver.part2(input),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    /// The solver didn't finish in time.
+    Timeout,
+    /// The solver returned an error.
+    Error(Error),
+    /// The solver panicked.
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Unimplemented => write!(f, "unimplemented"),
+            Status::Timeout => write!(f, "TIMEOUT"),
+            Status::Error(err) => write!(f, "error: {}", err.kind),
+            Status::Failed(msg) => write!(f, "failed: {}", msg),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub status: Status,
+    /// Wall time of the part, including any parsing it does itself.
+    pub time: Option<Duration>,
+    /// Time of the solver's standalone parsing step, shared by both parts.
+    pub parse_time: Option<Duration>,
+}
+
+/// Every year with at least one registered solver.
+pub fn implemented_years() -> Vec<u16> {
+    let mut years: Vec<u16> = registry::registrations()
+        .iter()
+        .map(|reg| reg.year)
+        .collect();
+    years.dedup();
+    years
+}
+
+/// Every day of `year` with a registered solver.
+pub fn implemented_days(year: u16) -> Vec<u8> {
+    registry::registrations()
+        .iter()
+        .filter(|reg| reg.year == year)
+        .map(|reg| reg.day)
+        .collect()
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct RunConfig {
+    /// Number of days run at the same time.
+    pub jobs: usize,
+    /// Time after which a part, or the parsing step, is given up on.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            jobs: 1,
+            timeout: None,
+        }
+    }
+}
+
+/// Runs the requested parts of several days on `config.jobs` threads. The
+/// results are passed to `report` as they come in, but always in the order of
+/// `days`, and returned in that order as well.
+pub fn run_days(
+    year: u16,
+    days: &[u8],
+    parts: &[Part],
+    input: &InputConfig,
+    config: RunConfig,
+    mut report: impl FnMut(&PartResult),
+) -> Vec<PartResult> {
+    let next_day = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..config.jobs.clamp(1, days.len().max(1)) {
+            let tx = tx.clone();
+            let next_day = &next_day;
+            thread::Builder::new()
+                .stack_size(SOLVER_STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let idx = next_day.fetch_add(1, Ordering::Relaxed);
+                    let Some(&day) = days.get(idx) else {
+                        break;
+                    };
+                    let results = run_day(year, day, parts, input, config.timeout);
+                    if tx.send((idx, results)).is_err() {
+                        break;
+                    }
+                })
+                .expect("failed to spawn a worker thread");
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut results = Vec::new();
+        for (idx, day_results) in rx {
+            pending.insert(idx, day_results);
+            while let Some(day_results) = pending.remove(&results.len()) {
+                day_results.iter().for_each(&mut report);
+                results.push(day_results);
+            }
+        }
+        results.into_iter().flatten().collect()
+    })
+}
+
+/// Runs the requested parts of a single day.
+///
+/// Errors returned by the solver, panics inside it (including
+/// `unimplemented!`) and a missing input are reported in the result
+/// status so that the rest of a batch keeps going. So is a part that takes
+/// longer than `timeout`, it is left running in the background.
+pub fn run_day(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    input: &InputConfig,
+    timeout: Option<Duration>,
+) -> Vec<PartResult> {
+    let result = |part, answer, status| PartResult {
+        year,
+        day,
+        part,
+        answer,
+        status,
+        time: None,
+        parse_time: None,
+    };
+
+    let (solver, input) = match prepare(input, year, day) {
+        Ok(prepared) => prepared,
+        Err(status) => {
+            return parts
+                .iter()
+                .map(|&part| result(part, None, status.clone()))
+                .collect();
+        }
+    };
+
+    let input: Arc<str> = input.into();
+    let parse_time = with_timeout(timeout, {
+        let (solver, input) = (Arc::clone(&solver), Arc::clone(&input));
+        move || catch_panic(|| timed(|| solver.parse(&input)))
+    })
+    .and_then(|res| res.ok())
+    .and_then(|(parsed, time)| parsed?.ok().map(|_| time));
+
+    parts
+        .iter()
+        .map(|&part| {
+            let (solver, input) = (Arc::clone(&solver), Arc::clone(&input));
+            let (answer, status, time) =
+                with_timeout(timeout, move || run_part(&*solver, day, part, &input)).unwrap_or((
+                    None,
+                    Status::Timeout,
+                    None,
+                ));
+            PartResult {
+                time,
+                parse_time,
+                ..result(part, answer, status)
+            }
+        })
+        .collect()
+}
+
+/// The solver of a day, set up with the puzzle parameters of the run, and
+/// the input to give it.
+pub(crate) fn prepare(
+    input: &InputConfig,
+    year: u16,
+    day: u8,
+) -> std::result::Result<(Arc<dyn Solution>, String), Status> {
+    let solver = get_solver(year, day).ok_or(Status::Unimplemented)?;
+    let (input, params) = input.read_with_params(year, day).map_err(Status::Error)?;
+    let solver: Arc<dyn Solution> = match solver.with_params(&params) {
+        Some(solver) => solver.map_err(|err| Status::Error(err.in_day(day)))?.into(),
+        None if params.is_empty() => Arc::new(solver),
+        None => {
+            let err = Error::input(format!("the day takes no parameters, got {}", params));
+            return Err(Status::Error(err.in_day(day)));
+        }
+    };
+    Ok((solver, input))
+}
+
+/// Runs one part, timing it when it succeeds.
+pub(crate) fn run_part(
+    solver: &dyn Solution,
+    day: u8,
+    part: Part,
+    input: &str,
+) -> (Option<Answer>, Status, Option<Duration>) {
+    match catch_panic(|| timed(|| part.solve(solver, input))) {
+        Ok((Ok(answer), time)) => (Some(answer), Status::Ok, Some(time)),
+        Ok((Err(err), _)) => (None, Status::Error(err.in_part(day, part)), None),
+        Err(msg) if msg.starts_with("not implemented") => (None, Status::Unimplemented, None),
+        Err(msg) => (None, Status::Failed(msg), None),
+    }
+}
+
+/// Runs `f` on a thread of its own and waits for it up to `timeout`. If it
+/// takes longer it is left running, there is no way to stop a thread.
+fn with_timeout<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Option<T> {
+    let Some(timeout) = timeout else {
+        return Some(f());
+    };
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let _ = tx.send(f());
+        })
+        .expect("failed to spawn a solver thread");
+    rx.recv_timeout(timeout).ok()
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+thread_local! {
+    static QUIET_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, turning a panic into its message. The panic hook is silenced
+/// for the current thread meanwhile, the message ends up in the summary
+/// instead. Other threads keep reporting their panics.
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T) -> std::result::Result<T, String> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET_PANICS.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+
+    let quiet = QUIET_PANICS.with(|q| q.replace(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET_PANICS.with(|q| q.set(quiet));
+
+    res.map_err(|payload| {
+        if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else {
+            "solver panicked".to_string()
+        }
+    })
+}
+
+pub fn format_duration(time: Option<Duration>) -> String {
+    time.map_or_else(|| "-".to_string(), |time| format!("{:.2?}", time))
+}
+
+pub fn print_summary(results: &[PartResult]) {
+    let answers: Vec<String> = results
+        .iter()
+        .map(|res| res.answer.as_ref().map_or("-".to_string(), Answer::summary))
+        .collect();
+    let width = answers
+        .iter()
+        .map(|answer| answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>10}  {:>10}  Status",
+        "Day", "Part", "Answer", "Time", "Parse"
+    );
+    for (res, answer) in results.iter().zip(answers) {
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>10}  {:>10}  {}",
+            res.day,
+            res.part,
+            answer,
+            format_duration(res.time),
+            format_duration(res.parse_time),
+            res.status
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    use crate::register;
+    use std::env;
+    use std::fs;
+
+    /// Solvers of a year of their own, so that they don't mix with real ones.
+    const YEAR: u16 = 1;
+
+    struct Sleepy(u64);
+    register!(YEAR, 1, Sleepy(2000));
+    register!(YEAR, 2, Sleepy(0));
+    register!(YEAR, 3, Sleepy(50));
+
+    impl Solution for Sleepy {
+        fn part1(&self, input: &str) -> Result<Answer> {
+            thread::sleep(Duration::from_millis(self.0));
+            Ok(Answer::parse(input))
+        }
+
+        fn part2(&self, _input: &str) -> Result<Answer> {
+            panic!("not implemented")
+        }
+    }
+
+    #[test]
+    fn test_run_days() {
+        let path = env::temp_dir().join(format!("aoc-runner-{}.txt", std::process::id()));
+        fs::write(&path, "42\n").unwrap();
+        let input = InputConfig {
+            source: InputSource::File(path.clone()),
+            ..InputConfig::default()
+        };
+        let config = RunConfig {
+            jobs: 3,
+            timeout: Some(Duration::from_millis(500)),
+        };
+
+        let mut reported = Vec::new();
+        let results = run_days(YEAR, &[1, 2, 3, 4], &Part::BOTH, &input, config, |res| {
+            reported.push((res.day, res.part))
+        });
+        let order: Vec<_> = results.iter().map(|res| (res.day, res.part)).collect();
+        assert_eq!(order, reported);
+        assert_eq!(
+            order,
+            [1, 2, 3, 4]
+                .into_iter()
+                .flat_map(|day| Part::BOTH.map(|part| (day, part)))
+                .collect::<Vec<_>>()
+        );
+
+        let statuses: Vec<_> = results.iter().map(|res| res.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            [
+                Status::Timeout,
+                Status::Unimplemented,
+                Status::Ok,
+                Status::Unimplemented,
+                Status::Ok,
+                Status::Unimplemented,
+                Status::Unimplemented,
+                Status::Unimplemented,
+            ]
+        );
+        assert_eq!(results[2].answer, Some(42.into()));
+        fs::remove_file(&path).unwrap();
+    }
+}
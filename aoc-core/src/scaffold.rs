@@ -0,0 +1,208 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::example::Example;
+use crate::input::{self, DataDir};
+use crate::runner::Part;
+use crate::Answer;
+
+/// The skeleton new days are made from, relative to the workspace root.
+/// It is shared by all years.
+pub const TEMPLATE_PATH: &str = "aoc-2024/src/day_template.rs";
+
+const TEMPLATE_NAME: &str = "DayXY";
+const TEMPLATE_REGISTRATION: &str = "register!(2024, XY, DayXY);";
+const TEMPLATE_INPUT: &str = "let input: &str = \"\";";
+const TEMPLATE_ANSWER: &str = "Ok(0.into())";
+
+fn src_dir(year: u16) -> PathBuf {
+    input::workspace_dir().join(format!("aoc-{}/src", year))
+}
+
+/// Creates the solver of a new day from the template, adds it to the year
+/// crate and creates an empty input file for it in `data_dir`. With an example, its input
+/// and expected answers go into the tests.
+///
+/// Refuses to touch a day that already exists. An existing input file is
+/// kept as it is. Returns the paths of the files that were written.
+pub fn new_day(
+    year: u16,
+    day: u8,
+    data_dir: &DataDir,
+    example: Option<&Example>,
+) -> io::Result<Vec<PathBuf>> {
+    let src_dir = src_dir(year);
+    let lib_path = src_dir.join("lib.rs");
+    let day_path = src_dir.join(format!("day{:02}.rs", day));
+    let input_path = data_dir.input_path(year, day);
+    let template_path = input::workspace_dir().join(TEMPLATE_PATH);
+
+    let lib = fs::read_to_string(&lib_path).map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!("can't read {}: {}", lib_path.display(), err),
+        )
+    })?;
+    if day_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", day_path.display()),
+        ));
+    }
+    let lib = add_module(&lib, day).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("day {} is already declared in {}", day, lib_path.display()),
+        )
+    })?;
+    let template = fs::read_to_string(&template_path).map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!("can't read template {}: {}", template_path.display(), err),
+        )
+    })?;
+    let source = render(&template, year, day, example).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "template {} lacks `{}`",
+                TEMPLATE_PATH, TEMPLATE_REGISTRATION
+            ),
+        )
+    })?;
+
+    fs::write(&day_path, source)?;
+    fs::write(&lib_path, lib)?;
+    let mut written = vec![day_path, lib_path];
+    if !input_path.exists() {
+        if let Some(dir) = input_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&input_path, "")?;
+        written.push(input_path);
+    }
+    Ok(written)
+}
+
+/// Fills in the template for `day`, or `None` if the template has no
+/// registration to fill in.
+fn render(template: &str, year: u16, day: u8, example: Option<&Example>) -> Option<String> {
+    if !template.contains(TEMPLATE_REGISTRATION) {
+        return None;
+    }
+    let source = template
+        .replace(
+            TEMPLATE_REGISTRATION,
+            &format!("register!({}, {}, {});", year, day, TEMPLATE_NAME),
+        )
+        .replace(TEMPLATE_NAME, &format!("Day{:02}", day));
+    let Some(example) = example else {
+        return Some(source);
+    };
+
+    // The tests come in order, so the first placeholders from the start of a
+    // test on are its own.
+    let input = format!("let input: &str = {};", string_literal(&example.input));
+    let mut source = source;
+    for part in Part::BOTH {
+        let Some(start) = source.find(&format!("fn test_part{}()", part)) else {
+            continue;
+        };
+        let (head, test) = source.split_at(start);
+        let mut test = test.replacen(TEMPLATE_INPUT, &input, 1);
+        if let Some(answer) = example.expected(part) {
+            test = test.replacen(TEMPLATE_ANSWER, &answer_literal(&answer), 1);
+        }
+        source = format!("{}{}", head, test);
+    }
+    Some(source)
+}
+
+/// A string literal in the style of the tests, with the lines of the input
+/// starting at the beginning of the source lines.
+fn string_literal(input: &str) -> String {
+    let escaped = input
+        .trim_end_matches('\n')
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    format!("\"{}\"", escaped)
+}
+
+fn answer_literal(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(n) => format!("Ok({}.into())", n),
+        answer => format!("Ok(Answer::parse({}))", string_literal(&answer.to_string())),
+    }
+}
+
+/// Adds `pub mod dayNN;` to the module list of `lib`, keeping it sorted.
+/// Returns `None` if the module is already there.
+fn add_module(lib: &str, day: u8) -> Option<String> {
+    let module = format!("pub mod day{:02};", day);
+    if lib.lines().any(|line| line.trim() == module) {
+        return None;
+    }
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&idx| lines[idx].starts_with("pub mod day"))
+        .collect();
+    let idx = modules
+        .iter()
+        .copied()
+        .find(|&idx| lines[idx] > module.as_str())
+        .or_else(|| modules.last().map(|&idx| idx + 1))
+        .unwrap_or(0);
+    lines.insert(idx, &module);
+
+    let mut lib = lines.join("\n");
+    lib.push('\n');
+    Some(lib)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = include_str!("../../aoc-2024/src/day_template.rs");
+
+    #[test]
+    fn test_render() {
+        let source = render(TEMPLATE, 2023, 7, None).unwrap();
+        assert!(source.contains("pub struct Day07;\nregister!(2023, 7, Day07);\n"));
+        assert!(!source.contains("XY"));
+        assert_eq!(source.matches(TEMPLATE_INPUT).count(), 2);
+
+        assert_eq!(render("pub struct DayXY;", 2023, 7, None), None);
+    }
+
+    #[test]
+    fn test_render_example() {
+        let example = Example::parse("part1: 11\n---\n3 \"4\"\n4 3\n").unwrap();
+        let source = render(TEMPLATE, 2024, 19, Some(&example)).unwrap();
+        assert_eq!(
+            source
+                .matches("let input: &str = \"3 \\\"4\\\"\n4 3\";")
+                .count(),
+            2
+        );
+        let (part1, part2) = source.split_once("fn test_part2").unwrap();
+        assert!(part1.contains("assert_eq!(result, Ok(11.into()));"));
+        assert!(part2.contains("assert_eq!(result, Ok(0.into()));"));
+    }
+
+    #[test]
+    fn test_add_module() {
+        let lib = "pub mod day01;\npub mod day03;\n\n#[cfg(test)]\nmod tests {}\n";
+        assert_eq!(
+            add_module(lib, 2).unwrap(),
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\n#[cfg(test)]\nmod tests {}\n"
+        );
+        assert_eq!(
+            add_module(lib, 4).unwrap(),
+            "pub mod day01;\npub mod day03;\npub mod day04;\n\n#[cfg(test)]\nmod tests {}\n"
+        );
+        assert_eq!(add_module(lib, 3), None);
+        assert_eq!(add_module("", 1).unwrap(), "pub mod day01;\n");
+    }
+}
@@ -0,0 +1,85 @@
+//! A stand-in for the puzzle server, for tests of the code that talks to it.
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::fetch::FetchConfig;
+
+#[derive(Clone, Debug)]
+pub struct Request {
+    /// Request line and headers, lowercased.
+    pub head: String,
+    pub body: String,
+}
+
+pub struct StubServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    /// Serves every request with the status and body `handler` returns.
+    pub fn start(handler: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&mut stream);
+                let mut head = String::new();
+                while !head.ends_with("\r\n\r\n") {
+                    if reader.read_line(&mut head).unwrap() == 0 {
+                        break;
+                    }
+                }
+                let head = head.to_lowercase();
+                let length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .map_or(0, |len| len.trim().parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let request = Request {
+                    head,
+                    body: String::from_utf8(body).unwrap(),
+                };
+
+                let (status, body) = handler(&request);
+                seen.lock().unwrap().push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        Self { base_url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// A config for this server with an empty cache directory of its own.
+    pub fn config(&self, name: &str) -> FetchConfig {
+        let cache_dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        FetchConfig {
+            base_url: self.base_url.clone(),
+            session: Some("c0ffee".to_string()),
+            contact: Some("me@example.com".to_string()),
+            cache_dir,
+            interval: Duration::ZERO,
+        }
+    }
+}
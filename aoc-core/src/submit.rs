@@ -0,0 +1,517 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::fetch::{self, FetchConfig};
+use crate::runner::Part;
+use crate::{Answer, Error, Result};
+
+/// What the server made of a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+    /// An answer was submitted too recently, nothing was checked.
+    Wait(Duration),
+    /// The part is solved already, or it isn't unlocked yet.
+    WrongLevel,
+}
+
+impl Response {
+    /// Reads the response from the page the server answers with.
+    pub fn parse(page: &str) -> Option<Response> {
+        if page.contains("That's the right answer") {
+            Some(Response::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Response::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Response::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Response::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Some(Response::Wait(parse_wait(page).unwrap_or_default()))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Response::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the answer was checked at all.
+    pub fn is_verdict(&self) -> bool {
+        !matches!(self, Response::Wait(_) | Response::WrongLevel)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Response::Correct => "correct",
+            Response::TooHigh => "too high",
+            Response::TooLow => "too low",
+            Response::Wrong => "wrong",
+            Response::Wait(_) => "wait",
+            Response::WrongLevel => "wrong level",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Response> {
+        [
+            Response::Correct,
+            Response::TooHigh,
+            Response::TooLow,
+            Response::Wrong,
+        ]
+        .into_iter()
+        .find(|response| response.name() == name)
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::TooHigh => write!(f, "wrong, too high"),
+            Response::TooLow => write!(f, "wrong, too low"),
+            Response::Wrong => write!(f, "wrong"),
+            Response::Wait(wait) => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Response::WrongLevel => write!(f, "part already solved or still locked"),
+        }
+    }
+}
+
+/// How long the server wants us to wait before the next answer, from either
+/// "You have 1m 5s left to wait" or "please wait 5 minutes".
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some(idx) = page.find(" left to wait") {
+        let start = page[..idx].rfind("You have ")? + "You have ".len();
+        let mut secs = 0;
+        for token in page[start..idx].split_whitespace() {
+            let (n, unit) = token.split_at(token.len() - 1);
+            let n: u64 = n.parse().ok()?;
+            secs += match unit {
+                "h" => n * 3600,
+                "m" => n * 60,
+                "s" => n,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(secs));
+    }
+    let idx = page.find("wait ")? + "wait ".len();
+    let mut words = page[idx..].split_whitespace();
+    let n = match words.next()? {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(60 * n))
+}
+
+/// Why an answer wasn't submitted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Refusal {
+    /// The part is solved already, with this answer.
+    Solved(Answer),
+    /// The same answer was rejected before.
+    Rejected(Response),
+    /// The answer isn't below an earlier one that was too high.
+    NotBelow(Answer),
+    /// The answer isn't above an earlier one that was too low.
+    NotAbove(Answer),
+    /// The server asked to wait this much longer.
+    Wait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "the part is already solved with {}", answer),
+            Refusal::Rejected(response) => write!(f, "it was already submitted: {}", response),
+            Refusal::NotBelow(bound) => write!(f, "{} was already too high", bound),
+            Refusal::NotAbove(bound) => write!(f, "{} was already too low", bound),
+            Refusal::Wait(wait) => write!(f, "the server asked to wait {}s more", wait.as_secs()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: Answer,
+    pub response: Response,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// The answers submitted for a day, kept so that answers known to be wrong
+/// are never sent again. Stored as TOML next to the cached input:
+///
+/// ```toml
+/// wait_until = 1733040000
+///
+/// [[attempt]]
+/// part = 1
+/// answer = "1234"
+/// response = "too high"
+/// time = 1733039940
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+    /// No answer is accepted before this time, in seconds since the epoch.
+    pub wait_until: Option<u64>,
+}
+
+pub fn history_path(config: &FetchConfig, year: u16, day: u8) -> PathBuf {
+    config
+        .cache_dir
+        .join(year.to_string())
+        .join(format!("day{:02}_submissions.toml", day))
+}
+
+fn invalid(path: &Path, msg: impl fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid submission history {}: {}", path.display(), msg),
+    )
+}
+
+impl History {
+    /// Loads the history from `path`. A missing file is an empty history.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+        Self::parse(&content).map_err(|msg| invalid(path, msg))
+    }
+
+    fn parse(content: &str) -> std::result::Result<Self, String> {
+        let table: toml::Table = content.parse().map_err(|err| format!("{}", err))?;
+        let wait_until = match table.get("wait_until") {
+            Some(toml::Value::Integer(time)) => Some(*time as u64),
+            Some(_) => return Err("`wait_until` is not a number".to_string()),
+            None => None,
+        };
+        let empty = Vec::new();
+        let entries = match table.get("attempt") {
+            Some(toml::Value::Array(entries)) => entries,
+            Some(_) => return Err("`attempt` is not an array of tables".to_string()),
+            None => &empty,
+        };
+        let attempts = entries
+            .iter()
+            .map(|entry| {
+                let part = entry
+                    .get("part")
+                    .and_then(|part| part.as_integer())
+                    .and_then(|part| Part::from_number(u8::try_from(part).ok()?));
+                let answer = entry.get("answer").and_then(|answer| answer.as_str());
+                let response = entry
+                    .get("response")
+                    .and_then(|response| response.as_str())
+                    .and_then(Response::from_name);
+                let time = entry.get("time").and_then(|time| time.as_integer());
+                match (part, answer, response, time) {
+                    (Some(part), Some(answer), Some(response), Some(time)) => Ok(Attempt {
+                        part,
+                        answer: Answer::parse(answer),
+                        response,
+                        time: time as u64,
+                    }),
+                    _ => Err(format!("malformed attempt {}", entry)),
+                }
+            })
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(Self {
+            attempts,
+            wait_until,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// Why `answer` shouldn't be submitted for `part` at `now`, if it can't
+    /// be the right one or the server wouldn't take it yet.
+    pub fn check(&self, part: Part, answer: &Answer, now: u64) -> Option<Refusal> {
+        let attempts = || self.attempts.iter().filter(|a| a.part == part);
+        if let Some(solved) = attempts().find(|a| a.response == Response::Correct) {
+            return Some(Refusal::Solved(solved.answer.clone()));
+        }
+        if let Some(same) = attempts().find(|a| &a.answer == answer) {
+            return Some(Refusal::Rejected(same.response.clone()));
+        }
+        if let Some(n) = answer.as_number() {
+            let bound = |response| {
+                attempts()
+                    .filter(move |a| a.response == response)
+                    .filter_map(|a| Some((a.answer.as_number()?, &a.answer)))
+            };
+            if let Some((_, high)) = bound(Response::TooHigh)
+                .filter(|&(h, _)| n >= h)
+                .min_by_key(|&(h, _)| h)
+            {
+                return Some(Refusal::NotBelow(high.clone()));
+            }
+            if let Some((_, low)) = bound(Response::TooLow)
+                .filter(|&(l, _)| n <= l)
+                .max_by_key(|&(l, _)| l)
+            {
+                return Some(Refusal::NotAbove(low.clone()));
+            }
+        }
+        match self.wait_until {
+            Some(until) if until > now => Some(Refusal::Wait(Duration::from_secs(until - now))),
+            _ => None,
+        }
+    }
+
+    /// Adds the server's response to an answer submitted at `now`. `page` is
+    /// the page it came with, which may ask to wait before the next answer.
+    pub fn record(
+        &mut self,
+        part: Part,
+        answer: &Answer,
+        response: &Response,
+        page: &str,
+        now: u64,
+    ) {
+        let wait = match response {
+            Response::Wait(wait) => Some(*wait),
+            _ => parse_wait(page),
+        };
+        if let Some(wait) = wait {
+            self.wait_until = Some(now + wait.as_secs());
+        }
+        if response.is_verdict() {
+            self.attempts.push(Attempt {
+                part,
+                answer: answer.clone(),
+                response: response.clone(),
+                time: now,
+            });
+        }
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut table = toml::Table::new();
+        if let Some(time) = self.wait_until {
+            table.insert("wait_until".to_string(), toml::Value::Integer(time as i64));
+        }
+        let attempts = self
+            .attempts
+            .iter()
+            .map(|attempt| {
+                let mut entry = toml::Table::new();
+                entry.insert(
+                    "part".into(),
+                    toml::Value::Integer(attempt.part.number().into()),
+                );
+                entry.insert(
+                    "answer".into(),
+                    toml::Value::String(attempt.answer.to_string()),
+                );
+                entry.insert(
+                    "response".into(),
+                    toml::Value::String(attempt.response.name().into()),
+                );
+                entry.insert("time".into(), toml::Value::Integer(attempt.time as i64));
+                toml::Value::Table(entry)
+            })
+            .collect();
+        table.insert("attempt".to_string(), toml::Value::Array(attempts));
+        table.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// The history shows the answer can't be right, it wasn't sent.
+    Refused(Refusal),
+    Answered(Response),
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+/// Submits `answer` for a part, unless the day's history shows it can't be
+/// right, and keeps the response in the history.
+pub fn submit(
+    config: &FetchConfig,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome> {
+    let path = history_path(config, year, day);
+    let history_error = |err: io::Error| Error::input(err.to_string()).in_day(day);
+    let mut history = History::load(&path).map_err(history_error)?;
+    if let Some(refusal) = history.check(part, answer, now()) {
+        return Ok(Outcome::Refused(refusal));
+    }
+    let Some(session) = &config.session else {
+        return Err(Error::input(fetch::missing_session("submit answers")).in_day(day));
+    };
+
+    let url = config.day_url(year, day, "answer");
+    let response = config.request(ureq::post, &url, session).send_form(&[
+        ("level", &part.to_string()),
+        ("answer", &answer.to_string()),
+    ]);
+    let page = fetch::read_response(&url, response).map_err(|err| err.in_day(day))?;
+    let response = Response::parse(&page)
+        .ok_or_else(|| Error::input(format!("unexpected response from {}", url)).in_day(day))?;
+
+    history.record(part, answer, &response, &page, now());
+    history.save(&path).map_err(history_error)?;
+    Ok(Outcome::Answered(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::StubServer;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    #[test]
+    fn test_parse_response() {
+        let page = |text| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Response::parse(&page(
+                "That's the right answer!  You are one gold star closer."
+            )),
+            Some(Response::Correct)
+        );
+        assert_eq!(Response::parse(TOO_HIGH), Some(Response::TooHigh));
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(Response::TooLow)
+        );
+        assert_eq!(
+            Response::parse(&page("That's not the right answer.  If you're stuck, ...")),
+            Some(Response::Wrong)
+        );
+        assert_eq!(
+            Response::parse(TOO_SOON),
+            Some(Response::Wait(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Response::parse(&page("You don't seem to be solving the right level.")),
+            Some(Response::WrongLevel)
+        );
+        assert_eq!(
+            Response::parse("<html>Puzzle inputs differ by user.</html>"),
+            None
+        );
+
+        assert_eq!(parse_wait(TOO_HIGH), Some(Duration::from_secs(60)));
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again"),
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+        history.record(Part::One, &100.into(), &Response::TooHigh, TOO_HIGH, 1000);
+        history.record(Part::One, &10.into(), &Response::TooLow, "", 1100);
+
+        let check =
+            |history: &History, answer: i64, now| history.check(Part::One, &answer.into(), now);
+        assert_eq!(
+            check(&history, 50, 1000),
+            Some(Refusal::Wait(Duration::from_secs(60)))
+        );
+        assert_eq!(check(&history, 50, 1060), None);
+        assert_eq!(
+            check(&history, 100, 1060),
+            Some(Refusal::Rejected(Response::TooHigh))
+        );
+        assert_eq!(
+            check(&history, 120, 1060),
+            Some(Refusal::NotBelow(100.into()))
+        );
+        assert_eq!(check(&history, 3, 1060), Some(Refusal::NotAbove(10.into())));
+        assert_eq!(history.check(Part::Two, &120.into(), 1060), None);
+
+        history.record(Part::One, &42.into(), &Response::Correct, "", 1200);
+        assert_eq!(check(&history, 43, 1300), Some(Refusal::Solved(42.into())));
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let mut history = History::default();
+        history.record(Part::One, &100.into(), &Response::TooHigh, TOO_HIGH, 1000);
+        history.record(Part::Two, &"abc".into(), &Response::Wrong, "", 1100);
+        history.record(
+            Part::Two,
+            &"abd".into(),
+            &Response::Wait(Duration::from_secs(5)),
+            "",
+            1101,
+        );
+        assert_eq!(history.attempts.len(), 2);
+        assert_eq!(history.wait_until, Some(1106));
+        assert_eq!(History::parse(&history.to_string()), Ok(history));
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = StubServer::start(|request| {
+            let page = match request.body.as_str() {
+                "level=1&answer=100" => TOO_HIGH,
+                "level=1&answer=42" => "<p>That's the right answer!</p>",
+                _ => "<p>That's not the right answer.</p>",
+            };
+            (200, page.to_string())
+        });
+        let config = server.config("submit");
+
+        let submit = |answer: i64| submit(&config, 2024, 3, Part::One, &answer.into());
+        assert_eq!(submit(100), Ok(Outcome::Answered(Response::TooHigh)));
+        assert!(matches!(submit(42), Ok(Outcome::Refused(Refusal::Wait(_)))));
+
+        // Let the lockout pass.
+        let path = history_path(&config, 2024, 3);
+        let mut history = History::load(&path).unwrap();
+        history.wait_until = None;
+        history.save(&path).unwrap();
+
+        assert_eq!(
+            submit(150),
+            Ok(Outcome::Refused(Refusal::NotBelow(100.into())))
+        );
+        assert_eq!(submit(42), Ok(Outcome::Answered(Response::Correct)));
+        assert_eq!(submit(42), Ok(Outcome::Refused(Refusal::Solved(42.into()))));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].head.starts_with("post /2024/day/3/answer "));
+        assert!(requests[0].head.contains("\r\ncookie: session=c0ffee\r\n"));
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,321 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use super::parse_token;
+use crate::{Error, Result};
+
+/// A position or offset on a grid, with `y` growing downwards.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coord {
+    pub const ORIGIN: Coord = Coord { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Distance when moving in four directions.
+    pub fn manhattan(self, other: Coord) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance when moving in eight directions, diagonals included.
+    pub fn chebyshev(self, other: Coord) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The neighbours up, right, down and left.
+    pub fn neighbors4(self) -> impl Iterator<Item = Coord> {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| self + dir.to_coord())
+    }
+
+    /// The neighbours including diagonal ones, clockwise from up.
+    pub fn neighbors8(self) -> impl Iterator<Item = Coord> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |dir| self + dir.to_coord())
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+    fn add(self, other: Coord) -> Coord {
+        Coord {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, other: Coord) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+    fn sub(self, other: Coord) -> Coord {
+        Coord {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, other: Coord) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+    fn neg(self) -> Coord {
+        Coord {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul<i32> for Coord {
+    type Output = Coord;
+    fn mul(self, factor: i32) -> Coord {
+        Coord {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+/// Parses `X,Y`.
+impl FromStr for Coord {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| Error::parse("a coordinate `X,Y`", s))?;
+        Ok(Coord {
+            x: parse_token(s, x, "integer")?,
+            y: parse_token(s, y, "integer")?,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn to_coord(&self) -> Coord {
+        match self {
+            Direction::Up => Coord { x: 0, y: -1 },
+            Direction::Down => Coord { x: 0, y: 1 },
+            Direction::Right => Coord { x: 1, y: 0 },
+            Direction::Left => Coord { x: -1, y: 0 },
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Right => Direction::Down,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Right => Direction::Left,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+/// Parses an arrow `^v<>`, a letter of `UDLR` or a compass point of `NSEW`,
+/// north being up.
+impl TryFrom<char> for Direction {
+    type Error = Error;
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            _ => Err(Error::parse("a direction", c.to_string())),
+        }
+    }
+}
+
+/// Parses a single character, see `TryFrom<char>`.
+impl FromStr for Direction {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(Error::parse("a direction", s)),
+        }
+    }
+}
+
+/// The directions to all eight neighbours on a grid.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Clockwise from north, which is up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn to_coord(&self) -> Coord {
+        match self {
+            Direction8::N => Coord { x: 0, y: -1 },
+            Direction8::NE => Coord { x: 1, y: -1 },
+            Direction8::E => Coord { x: 1, y: 0 },
+            Direction8::SE => Coord { x: 1, y: 1 },
+            Direction8::S => Coord { x: 0, y: 1 },
+            Direction8::SW => Coord { x: -1, y: 1 },
+            Direction8::W => Coord { x: -1, y: 0 },
+            Direction8::NW => Coord { x: -1, y: -1 },
+        }
+    }
+
+    /// Turns clockwise by 45 degrees.
+    pub fn turn_right(&self) -> Direction8 {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns counterclockwise by 45 degrees.
+    pub fn turn_left(&self) -> Direction8 {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(&self) -> Direction8 {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Direction8::N,
+            Direction::Down => Direction8::S,
+            Direction::Right => Direction8::E,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut pos = Coord::new(3, -2);
+        assert_eq!(pos - Coord::new(1, 1), Coord::new(2, -3));
+        assert_eq!(-pos, Coord::new(-3, 2));
+        assert_eq!(pos * 3, Coord::new(9, -6));
+        pos += Coord::new(1, 2);
+        assert_eq!(pos, Coord::new(4, 0));
+        pos -= Coord::new(4, 0);
+        assert_eq!(pos, Coord::ORIGIN);
+
+        assert_eq!(Coord::new(1, 1).manhattan(Coord::new(4, -3)), 7);
+        assert_eq!(Coord::new(1, 1).chebyshev(Coord::new(4, -3)), 4);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let pos = Coord::new(1, 1);
+        assert_eq!(
+            pos.neighbors4().collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (1, 2), (0, 1)].map(|(x, y)| Coord::new(x, y))
+        );
+        let neighbors: Vec<_> = pos.neighbors8().collect();
+        assert_eq!(neighbors.len(), 8);
+        assert!(neighbors.iter().all(|&n| pos.chebyshev(n) == 1));
+        assert_eq!(neighbors[1], Coord::new(2, 0));
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.to_coord() + dir.opposite().to_coord(), Coord::ORIGIN);
+            assert_eq!(Direction8::from(dir).to_coord(), dir.to_coord());
+        }
+        for dir in Direction8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(-dir.to_coord(), dir.opposite().to_coord());
+            assert_eq!(dir.turn_right().is_diagonal(), !dir.is_diagonal());
+        }
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        for (s, dir) in [("^UN", Direction::Up), ("vDS", Direction::Down)] {
+            for c in s.chars() {
+                assert_eq!(Direction::try_from(c), Ok(dir));
+            }
+        }
+        assert_eq!("R".parse(), Ok(Direction::Right));
+        assert_eq!("W".parse(), Ok(Direction::Left));
+        assert!("x".parse::<Direction>().is_err());
+        assert!("UD".parse::<Direction>().is_err());
+    }
+}
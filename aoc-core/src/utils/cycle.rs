@@ -0,0 +1,154 @@
+//! Cycle detection for simulations that are run for more steps than can be
+//! simulated one by one.
+//!
+//! States are told apart by a fingerprint, which can be the state itself if
+//! it is hashable. Only fingerprints are remembered, and every state is
+//! visited once, so the cycle is found when its start is first repeated.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a simulation starts repeating itself: the state after `start` steps
+/// comes back every `length` steps from then on.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps that leads to the same state as `steps`.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+}
+
+/// Runs `step` from `initial` until a state repeats.
+///
+/// Doesn't return if the states never repeat, see `after` for simulations
+/// that may not.
+pub fn find<S, K: Hash + Eq>(
+    initial: S,
+    mut step: impl FnMut(&mut S),
+    mut fingerprint: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut state = initial;
+    let mut seen = HashMap::new();
+    for steps in 0.. {
+        if let Some(start) = seen.insert(fingerprint(&state), steps) {
+            return Cycle {
+                start,
+                length: steps - start,
+            };
+        }
+        step(&mut state);
+    }
+    unreachable!("more steps than fit in usize")
+}
+
+/// The state after `steps` steps from `initial`. Once a state repeats, the
+/// remaining steps that only go round the cycle are skipped.
+pub fn after<S, K: Hash + Eq>(
+    initial: S,
+    steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut fingerprint: impl FnMut(&S) -> K,
+) -> S {
+    let mut state = initial;
+    let mut seen = HashMap::new();
+    let mut done = 0;
+    while done < steps {
+        if let Some(start) = seen.insert(fingerprint(&state), done) {
+            for _ in 0..(steps - done) % (done - start) {
+                step(&mut state);
+            }
+            return state;
+        }
+        step(&mut state);
+        done += 1;
+    }
+    state
+}
+
+/// A simulation going from state to state, which gets cycle detection with
+/// this trait.
+pub trait Simulate: Sized {
+    type Fingerprint: Hash + Eq;
+
+    fn step(&mut self);
+
+    /// Equal for two states exactly if they are the same.
+    fn fingerprint(&self) -> Self::Fingerprint;
+
+    fn find_cycle(self) -> Cycle {
+        find(self, Self::step, Self::fingerprint)
+    }
+
+    /// The state after `steps` steps, see `after`.
+    fn after(self, steps: usize) -> Self {
+        after(self, steps, Self::step, Self::fingerprint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `x² + 1` modulo 227 from 0, which starts repeating after 7 steps every
+    /// 23 steps.
+    #[derive(Debug)]
+    struct Rho(u64);
+
+    impl Simulate for Rho {
+        type Fingerprint = u64;
+
+        fn step(&mut self) {
+            self.0 = (self.0 * self.0 + 1) % 227;
+        }
+
+        fn fingerprint(&self) -> u64 {
+            self.0
+        }
+    }
+
+    fn rho(steps: usize) -> u64 {
+        let mut rho = Rho(0);
+        for _ in 0..steps {
+            rho.step();
+        }
+        rho.0
+    }
+
+    #[test]
+    fn test_find() {
+        let cycle = Rho(0).find_cycle();
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 7,
+                length: 23
+            }
+        );
+        assert_eq!(rho(7), rho(30));
+        assert_ne!(rho(6), rho(29));
+        assert_eq!(cycle.reduce(5), 5);
+        assert_eq!(cycle.reduce(7 + 23 * 1000 + 5), 12);
+    }
+
+    #[test]
+    fn test_after() {
+        for steps in [0, 1, 6, 7, 8, 29, 30, 31, 100] {
+            assert_eq!(Rho(0).after(steps).0, rho(steps));
+        }
+        assert_eq!(
+            Rho(0).after(1_000_000_000).0,
+            rho(7 + (1_000_000_000 - 7) % 23)
+        );
+        // States that never repeat are simply stepped through.
+        assert_eq!(after(0u64, 10, |n| *n += 1, |&n| n), 10);
+    }
+}
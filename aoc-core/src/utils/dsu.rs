@@ -0,0 +1,115 @@
+//! Disjoint sets (union-find) of the numbers `0..n`, for puzzles that join
+//! things into groups and ask which belong together.
+
+/// A partition of `0..n` into sets, each named by one of its elements. With
+/// path compression and union by size, every operation takes nearly
+/// constant time.
+#[derive(Clone, Debug)]
+pub struct Dsu {
+    /// The element each element points to on its way to the name of its set,
+    /// which points to itself.
+    parent: Vec<usize>,
+    /// Number of elements, only kept up to date for the names of the sets.
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl Dsu {
+    /// Every element of `0..n` in a set of its own.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            sets: n,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of sets.
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+
+    /// The name of the set `x` is in.
+    ///
+    /// Panics if `x` isn't an element.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point everything on the way straight to the name.
+        let mut x = x;
+        while self.parent[x] != root {
+            (x, self.parent[x]) = (self.parent[x], root);
+        }
+        root
+    }
+
+    /// Joins the sets of `a` and `b`, `false` if they already were one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (small, large) = if self.size[a] < self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.sets -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set of `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dsu() {
+        let mut dsu = Dsu::new(6);
+        assert_eq!((dsu.len(), dsu.set_count()), (6, 6));
+        assert!(dsu.union(0, 1));
+        assert!(dsu.union(2, 3));
+        assert!(dsu.union(1, 3));
+        assert!(!dsu.union(0, 2));
+        assert!(dsu.same(0, 3));
+        assert!(!dsu.same(0, 4));
+        assert_eq!((dsu.size(2), dsu.size(5)), (4, 1));
+        assert_eq!(dsu.set_count(), 3);
+        assert!(Dsu::new(0).is_empty());
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let n = 100_000;
+        let mut dsu = Dsu::new(n);
+        for x in 1..n {
+            dsu.union(x - 1, x);
+        }
+        assert_eq!(dsu.size(0), n);
+        assert!(dsu.same(0, n - 1));
+        assert_eq!(dsu.set_count(), 1);
+    }
+}
@@ -0,0 +1,440 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::dsu::Dsu;
+use super::Coord;
+use crate::{Error, Result};
+
+/// A rectangular grid of cells, addressed by `Coord` with `x` growing to the
+/// right and `y` downwards from the top left corner.
+///
+/// Indexing with `[coord]` panics outside the grid, `get` doesn't.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a grid with a line per row, turning each character into a cell
+    /// with `f`. Errors of `f` and rows of the wrong length are reported with
+    /// their position in `input`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut grid = Self {
+            width: 0,
+            height: 0,
+            cells: Vec::with_capacity(input.len()),
+        };
+        for line in input.lines() {
+            for (idx, c) in line.char_indices() {
+                let token = &line[idx..idx + c.len_utf8()];
+                grid.cells
+                    .push(f(c).map_err(|err| err.within(input, token))?);
+            }
+            let row_len = grid.cells.len() - grid.width * grid.height;
+            if grid.height == 0 {
+                grid.width = row_len;
+            } else if row_len != grid.width {
+                let expected = format!("a row of {} cells", grid.width);
+                return Err(Error::parse(expected, line).within(input, line));
+            }
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Coord) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    fn offset(&self, pos: Coord) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    fn coord(&self, offset: usize) -> Coord {
+        Coord {
+            x: (offset % self.width) as i32,
+            y: (offset / self.width) as i32,
+        }
+    }
+
+    pub fn get(&self, pos: Coord) -> Option<&T> {
+        self.offset(pos).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, pos: Coord) -> Option<&mut T> {
+        self.offset(pos).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every cell with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(offset, cell)| (self.coord(offset), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(offset, cell)| {
+                let pos = Coord {
+                    x: (offset % width) as i32,
+                    y: (offset / width) as i32,
+                };
+                (pos, cell)
+            })
+    }
+
+    /// Every coordinate inside the grid, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.cells.len()).map(|offset| self.coord(offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't take a size of 0, which empty rows have.
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} outside the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|offset| self.coord(offset))
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coord> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// A grid of `width` by `height` cells, each taken from this grid at `f`
+    /// of its coordinate.
+    fn rearranged(&self, width: usize, height: usize, f: impl Fn(Coord) -> Coord) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                let pos = Coord {
+                    x: x as i32,
+                    y: y as i32,
+                };
+                cells.push(self[f(pos)].clone());
+            }
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Mirrors the grid at its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |pos| Coord { x: pos.y, y: pos.x })
+    }
+
+    /// Rotates the grid clockwise by 90 degrees.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let bottom = self.height as i32 - 1;
+        self.rearranged(self.height, self.width, |pos| Coord {
+            x: pos.y,
+            y: bottom - pos.x,
+        })
+    }
+
+    /// Rotates the grid counterclockwise by 90 degrees.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let right = self.width as i32 - 1;
+        self.rearranged(self.height, self.width, |pos| Coord {
+            x: right - pos.y,
+            y: pos.x,
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let right = self.width as i32 - 1;
+        self.rearranged(self.width, self.height, |pos| Coord {
+            x: right - pos.x,
+            y: pos.y,
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let bottom = self.height as i32 - 1;
+        self.rearranged(self.width, self.height, |pos| Coord {
+            x: pos.x,
+            y: bottom - pos.y,
+        })
+    }
+
+    /// Splits the grid into regions of equal cells that are connected
+    /// horizontally or vertically.
+    pub fn label_regions(&self) -> Regions
+    where
+        T: PartialEq,
+    {
+        // Pairs of equal cells next to each other, each pair once.
+        let len = self.cells.len();
+        let pairs = (0..len).flat_map(|offset| {
+            let right = (offset % self.width + 1 < self.width).then_some(offset + 1);
+            let below = (offset + self.width < len).then_some(offset + self.width);
+            right
+                .into_iter()
+                .chain(below)
+                .map(move |other| (offset, other))
+        });
+
+        let mut dsu = Dsu::new(len);
+        for (a, b) in pairs.clone() {
+            if self.cells[a] == self.cells[b] {
+                dsu.union(a, b);
+            }
+        }
+
+        let mut ids = vec![None; len];
+        let mut sizes = Vec::with_capacity(dsu.set_count());
+        let mut labels = Vec::with_capacity(len);
+        for offset in 0..len {
+            let root = dsu.find(offset);
+            let id = *ids[root].get_or_insert_with(|| {
+                sizes.push(0);
+                sizes.len() - 1
+            });
+            sizes[id] += 1;
+            labels.push(id);
+        }
+
+        let mut adjacency = vec![BTreeMap::new(); sizes.len()];
+        for (a, b) in pairs {
+            let (a, b) = (labels[a], labels[b]);
+            if a != b {
+                *adjacency[a].entry(b).or_insert(0) += 1;
+                *adjacency[b].entry(a).or_insert(0) += 1;
+            }
+        }
+
+        Regions {
+            labels: Grid {
+                width: self.width,
+                height: self.height,
+                cells: labels,
+            },
+            sizes,
+            adjacency,
+        }
+    }
+}
+
+/// Connected regions of equal cells in a grid, see `Grid::label_regions`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Regions {
+    /// The region of each cell. Regions are numbered from 0 in the order
+    /// their first cells come row by row.
+    pub labels: Grid<usize>,
+    /// Number of cells of each region.
+    pub sizes: Vec<usize>,
+    /// The regions each region borders on, with the number of cell sides
+    /// they share.
+    pub adjacency: Vec<BTreeMap<usize, usize>>,
+}
+
+impl Regions {
+    /// Number of regions.
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coord) -> &T {
+        match self.offset(pos) {
+            Some(offset) => &self.cells[offset],
+            None => panic!(
+                "{:?} outside the grid of {}x{}",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, pos: Coord) -> &mut T {
+        match self.offset(pos) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!(
+                "{:?} outside the grid of {}x{}",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// The cells row by row, with a line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(input, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("12\n34\n56\n", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::parse("digit", c.to_string()))
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Coord { x: 1, y: 2 }], 6);
+        assert_eq!(grid.get(Coord { x: 2, y: 0 }), None);
+        assert_eq!(grid.get(Coord { x: 0, y: -1 }), None);
+        assert_eq!(grid.to_string(), "12\n34\n56");
+
+        let err = Grid::parse("12\n3x", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::parse("digit", c.to_string()))
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected digit, found \"x\""
+        );
+        let err = Grid::parse("12\n345", Ok).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a row of 2 cells, found \"345\""
+        );
+        assert_eq!(Grid::parse("", Ok).unwrap(), Grid::default());
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = grid("ab.\n.b.");
+        assert_eq!(grid.find(&'b'), Some(Coord { x: 1, y: 0 }));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(
+            grid.find_all(&'b').collect::<Vec<_>>(),
+            vec![Coord { x: 1, y: 0 }, Coord { x: 1, y: 1 }]
+        );
+        assert_eq!(grid.row(1), &['.', 'b', '.']);
+        assert_eq!(grid.column(0).collect::<String>(), "a.");
+        assert_eq!(grid.coords().last(), Some(Coord { x: 2, y: 1 }));
+
+        grid[Coord { x: 2, y: 1 }] = 'c';
+        *grid.get_mut(Coord { x: 0, y: 1 }).unwrap() = 'd';
+        assert_eq!(grid.to_string(), "ab.\ndbc");
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "AB.\nDBC");
+    }
+
+    #[test]
+    fn test_rearrange() {
+        let grid = grid("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+
+    #[test]
+    fn test_label_regions() {
+        let regions = grid("AAB\nABB\nCAA").label_regions();
+        assert_eq!(regions.len(), 4);
+        assert_eq!(regions.labels.to_string(), "001\n011\n233");
+        assert_eq!(regions.sizes, vec![3, 3, 1, 2]);
+        assert_eq!(regions.adjacency[1], BTreeMap::from([(0, 3), (3, 2)]));
+        assert_eq!(regions.adjacency[2], BTreeMap::from([(0, 1), (3, 1)]));
+        assert!(Grid::<char>::default().label_regions().is_empty());
+    }
+}
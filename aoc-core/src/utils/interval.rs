@@ -0,0 +1,356 @@
+//! Sets of integers made of whole ranges, and maps that shift such ranges,
+//! for puzzles where there are far too many numbers to handle one by one.
+
+use std::fmt;
+
+use crate::{Error, Result};
+
+/// The integers from `start` up to but not including `end`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// The `len` integers from `start` on.
+    pub fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The integers in both, `None` if there are none.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let common = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!common.is_empty()).then_some(common)
+    }
+
+    /// Every integer moved by `offset`.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that are neither empty nor
+/// touch each other.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Adds the integers of `interval`, merging it with the intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| {
+                Interval::new(merged.start.min(other.start), merged.end.max(other.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        // Both are sorted, so a merge finds all overlaps.
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { intervals }
+    }
+
+    /// The integers of this set that aren't in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            let first = other.intervals.partition_point(|cut| cut.end <= rest.start);
+            for cut in &other.intervals[first..] {
+                if cut.start >= rest.end {
+                    break;
+                }
+                if cut.start > rest.start {
+                    intervals.push(Interval::new(rest.start, cut.start));
+                }
+                rest.start = cut.end;
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        RangeSet { intervals }
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = RangeSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+/// A map from integers to integers that adds an offset, which depends on the
+/// interval an integer is in. Integers outside of all intervals map to
+/// themselves.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RangeMap {
+    /// Sorted and disjoint.
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the integers of `source` by adding `offset`. Fails if `source`
+    /// overlaps an interval mapped before.
+    pub fn insert(&mut self, source: Interval, offset: i64) -> Result<()> {
+        if source.is_empty() {
+            return Ok(());
+        }
+        let idx = self
+            .pieces
+            .partition_point(|(other, _)| other.end <= source.start);
+        if let Some((other, _)) = self
+            .pieces
+            .get(idx)
+            .filter(|(other, _)| other.start < source.end)
+        {
+            return Err(Error::input(format!(
+                "the mapped ranges {} and {} overlap",
+                other, source
+            )));
+        }
+        self.pieces.insert(idx, (source, offset));
+        Ok(())
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let idx = self
+            .pieces
+            .partition_point(|(source, _)| source.end <= value);
+        match self.pieces.get(idx) {
+            Some(&(source, offset)) if source.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// The pieces including the unmapped gaps between them, which have an
+    /// offset of 0, from `i64::MIN` to `i64::MAX`.
+    fn segments(&self) -> Vec<(Interval, i64)> {
+        let mut segments = Vec::with_capacity(2 * self.pieces.len() + 1);
+        let mut start = i64::MIN;
+        for &(source, offset) in &self.pieces {
+            if start < source.start {
+                segments.push((Interval::new(start, source.start), 0));
+            }
+            segments.push((source, offset));
+            start = source.end;
+        }
+        if start < i64::MAX {
+            segments.push((Interval::new(start, i64::MAX), 0));
+        }
+        segments
+    }
+
+    /// Where the integers of `set` are mapped to.
+    pub fn map_set(&self, set: &RangeSet) -> RangeSet {
+        let mut image = RangeSet::new();
+        for (source, offset) in self.segments() {
+            for interval in set.intersection(&source.into()).intervals {
+                image.insert(interval.shift(offset));
+            }
+        }
+        image
+    }
+
+    /// The map that applies this map and then `next`.
+    pub fn then(&self, next: &RangeMap) -> RangeMap {
+        let mut composed = RangeMap::new();
+        for (source, offset) in self.segments() {
+            let target = source.shift(offset);
+            for (next_source, next_offset) in next.segments() {
+                if let Some(common) = target.intersection(&next_source) {
+                    composed.push(common.shift(-offset), offset + next_offset);
+                }
+            }
+        }
+        composed
+    }
+
+    /// Adds a piece after all others, merging it with the last one if it
+    /// continues it with the same offset. Pieces that map to themselves are
+    /// left out.
+    fn push(&mut self, source: Interval, offset: i64) {
+        if offset == 0 {
+            return;
+        }
+        match self.pieces.last_mut() {
+            Some((last, last_offset)) if last.end == source.start && *last_offset == offset => {
+                last.end = source.end;
+            }
+            _ => self.pieces.push((source, offset)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::with_len(3, 4);
+        assert_eq!(interval, Interval::new(3, 7));
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(3) && !interval.contains(7));
+        assert_eq!(
+            interval.intersection(&Interval::new(5, 10)),
+            Some(Interval::new(5, 7))
+        );
+        assert_eq!(interval.intersection(&Interval::new(7, 10)), None);
+        assert!(Interval::new(2, 2).is_empty());
+        assert_eq!(interval.shift(-3).to_string(), "0..4");
+    }
+
+    #[test]
+    fn test_set() {
+        let a = set(&[(5, 8), (0, 2), (1, 3), (3, 4), (10, 10)]);
+        assert_eq!(a.intervals(), set(&[(0, 4), (5, 8)]).intervals());
+        assert_eq!((a.len(), a.min(), a.max()), (7, Some(0), Some(7)));
+        assert!(a.contains(3) && !a.contains(4) && !a.contains(8));
+
+        let b = set(&[(2, 6), (7, 12)]);
+        assert_eq!(a.union(&b), set(&[(0, 12)]));
+        assert_eq!(a.intersection(&b), set(&[(2, 4), (5, 6), (7, 8)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (6, 7)]));
+        assert_eq!(b.difference(&a), set(&[(4, 5), (8, 12)]));
+        assert_eq!(a.difference(&RangeSet::new()), a);
+        assert!(RangeSet::new().intersection(&a).is_empty());
+    }
+
+    #[test]
+    fn test_map() {
+        // 50..98 -> 52..100, 98..100 -> 50..52
+        let mut seed_to_soil = RangeMap::new();
+        seed_to_soil.insert(Interval::new(98, 100), -48).unwrap();
+        seed_to_soil.insert(Interval::new(50, 98), 2).unwrap();
+        assert!(seed_to_soil.insert(Interval::new(90, 110), 1).is_err());
+        assert_eq!(
+            [0, 50, 97, 98, 99, 100].map(|seed| seed_to_soil.get(seed)),
+            [0, 52, 99, 50, 51, 100]
+        );
+        assert_eq!(
+            seed_to_soil.map_set(&set(&[(45, 55), (97, 99)])),
+            set(&[(45, 50), (52, 57), (50, 51), (99, 100)])
+        );
+
+        let mut double_shift = RangeMap::new();
+        double_shift.insert(Interval::new(0, 60), 10).unwrap();
+        let composed = seed_to_soil.then(&double_shift);
+        for seed in [0, 45, 50, 57, 58, 97, 98, 99, 100, 1000] {
+            assert_eq!(composed.get(seed), double_shift.get(seed_to_soil.get(seed)));
+        }
+        let seeds = set(&[(40, 110)]);
+        assert_eq!(
+            composed.map_set(&seeds),
+            double_shift.map_set(&seed_to_soil.map_set(&seeds))
+        );
+        assert_eq!(RangeMap::new().then(&RangeMap::new()), RangeMap::new());
+    }
+}
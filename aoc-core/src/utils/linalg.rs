@@ -0,0 +1,264 @@
+//! Exact linear algebra over fractions, for systems of equations whose
+//! solutions have to be checked for being whole numbers.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::math::{gcd, Integer};
+
+/// A fraction in lowest terms, with a positive denominator.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// `numer / denom`, reduced.
+    ///
+    /// Panics if `denom` is 0.
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(denom != T::ZERO, "fraction {}/0", numer);
+        let divisor = gcd(numer, denom);
+        let sign = if denom < T::ZERO { -T::ONE } else { T::ONE };
+        Self {
+            numer: sign * numer / divisor,
+            denom: sign * denom / divisor,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::from(T::ZERO)
+    }
+
+    pub fn one() -> Self {
+        Self::from(T::ONE)
+    }
+
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer == T::ZERO
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::ONE
+    }
+
+    /// The value if it is a whole number.
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numer)
+    }
+}
+
+impl<T: Integer> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Self {
+            numer: value,
+            denom: T::ONE,
+        }
+    }
+}
+
+// The operators cancel common factors before multiplying, which keeps the
+// intermediate values small.
+
+impl<T: Integer> Add for Rational<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let divisor = gcd(self.denom, other.denom);
+        let (self_factor, other_factor) = (other.denom / divisor, self.denom / divisor);
+        Self::new(
+            self.numer * self_factor + other.numer * other_factor,
+            self.denom * self_factor,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Rational<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<T: Integer> Mul for Rational<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = (gcd(self.numer, other.denom), gcd(other.numer, self.denom));
+        Self::new(
+            (self.numer / a) * (other.numer / b),
+            (self.denom / b) * (other.denom / a),
+        )
+    }
+}
+
+impl<T: Integer> Div for Rational<T> {
+    type Output = Self;
+
+    /// Panics if `other` is 0.
+    fn div(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "division of {} by 0", self);
+        self * Self::new(other.denom, other.numer)
+    }
+}
+
+impl<T: Integer> Neg for Rational<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+/// Determinant of the 2x2 matrix with the rows `a`.
+pub fn det2<T: Integer>(a: [[T; 2]; 2]) -> T {
+    a[0][0] * a[1][1] - a[0][1] * a[1][0]
+}
+
+/// Solves `a * x = b` for a 2x2 matrix `a` by Cramer's rule, `None` if the
+/// determinant is 0.
+pub fn solve2<T: Integer>(a: [[T; 2]; 2], b: [T; 2]) -> Option<[Rational<T>; 2]> {
+    let det = det2(a);
+    if det == T::ZERO {
+        return None;
+    }
+    Some([
+        Rational::new(det2([[b[0], a[0][1]], [b[1], a[1][1]]]), det),
+        Rational::new(det2([[a[0][0], b[0]], [a[1][0], b[1]]]), det),
+    ])
+}
+
+/// Solves `a * x = b` for a square matrix `a`, given by its rows, with
+/// Gaussian elimination. `None` if `a` is singular, so that there is no or
+/// no single solution.
+///
+/// Panics if `a` isn't square or `b` doesn't have a value per row.
+pub fn solve<T: Integer>(a: &[Vec<Rational<T>>], b: &[Rational<T>]) -> Option<Vec<Rational<T>>> {
+    let n = a.len();
+    assert!(
+        b.len() == n && a.iter().all(|row| row.len() == n),
+        "not a square system of equations"
+    );
+    // Each row ends with its value of `b`.
+    let mut rows: Vec<Vec<Rational<T>>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &value)| row.iter().copied().chain([value]).collect())
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| !rows[row][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+        for (idx, row) in rows.iter_mut().enumerate() {
+            if idx == col || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col] / pivot_row[col];
+            for (cell, &pivot_cell) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *cell = *cell - factor * pivot_cell;
+            }
+        }
+    }
+    Some(
+        rows.iter()
+            .enumerate()
+            .map(|(idx, row)| row[n] / row[idx])
+            .collect(),
+    )
+}
+
+/// The solution as whole numbers, if all its values are.
+pub fn integer_solution<T: Integer>(solution: &[Rational<T>]) -> Option<Vec<T>> {
+    solution.iter().map(Rational::to_integer).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(numer: i128, denom: i128) -> Rational<i128> {
+        Rational::new(numer, denom)
+    }
+
+    #[test]
+    fn test_rational() {
+        assert_eq!(r(4, -6), r(-2, 3));
+        assert_eq!((r(-2, 3).numer(), r(-2, 3).denom()), (-2, 3));
+        assert_eq!(r(1, 6) + r(1, 3), r(1, 2));
+        assert_eq!(r(1, 6) - r(1, 2), r(-1, 3));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+        assert_eq!(r(0, 5) * r(0, 7), Rational::zero());
+        assert!(r(1, 3) < r(1, 2) && r(-1, 2) < r(-1, 3));
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(7, 3).to_integer(), None);
+        assert_eq!(r(7, 3).to_string(), "7/3");
+        assert_eq!(Rational::from(-4i64).to_string(), "-4");
+    }
+
+    #[test]
+    fn test_solve2() {
+        // 94a + 22b = 8400, 34a + 67b = 5400
+        let [a, b] = solve2([[94i128, 22], [34, 67]], [8400, 5400]).unwrap();
+        assert_eq!((a, b), (r(80, 1), r(40, 1)));
+        let [a, _] = solve2([[2i64, 1], [1, 1]], [3, 1]).unwrap();
+        assert_eq!(a, Rational::new(2, 1));
+        assert!(solve2([[1i64, 2], [2, 4]], [3, 6]).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+        let int = |rows: &[&[i128]]| -> Vec<Vec<Rational<i128>>> {
+            rows.iter()
+                .map(|row| row.iter().map(|&v| v.into()).collect())
+                .collect()
+        };
+        let a = int(&[&[1, 1, 1], &[0, 2, 5], &[2, 5, -1]]);
+        let b = [6, -4, 27].map(Rational::from);
+        let x = solve(&a, &b).unwrap();
+        assert_eq!(integer_solution(&x), Some(vec![5, 3, -2]));
+
+        // The first pivot is 0, and the solution isn't whole.
+        let a = int(&[&[0, 2], &[3, 0]]);
+        let x = solve(&a, &[1.into(), 1.into()]).unwrap();
+        assert_eq!(x, vec![r(1, 3), r(1, 2)]);
+        assert_eq!(integer_solution(&x), None);
+
+        let a = int(&[&[1, 2], &[2, 4]]);
+        assert_eq!(solve(&a, &[1.into(), 2.into()]), None);
+    }
+}
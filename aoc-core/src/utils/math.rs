@@ -0,0 +1,259 @@
+//! Number theory on signed integers, for puzzles about things that repeat
+//! with different periods.
+//!
+//! Everything works on `i64` and `i128`. Whatever may overflow is checked
+//! and gives `None` or an error rather than a wrong result.
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::{Error, Result};
+
+/// The integer types the functions of this module work on.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+    fn rem_euclid(self, other: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($int:ty),+) => {
+        $(
+            impl Integer for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$int>::checked_abs(self)
+                }
+
+                fn rem_euclid(self, other: Self) -> Self {
+                    <$int>::rem_euclid(self, other)
+                }
+            }
+        )+
+    };
+}
+
+integer!(i64, i128);
+
+fn two<T: Integer>() -> T {
+    T::ONE + T::ONE
+}
+
+/// Greatest common divisor, which is never negative. `gcd(0, 0)` is 0.
+///
+/// Panics if the result doesn't fit, which only happens for `T::MIN`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.checked_abs().expect("gcd overflows")
+}
+
+/// Least common multiple, which is never negative, or `None` if it doesn't
+/// fit. The multiple of 0 and anything is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)?.checked_abs()
+}
+
+/// Greatest common divisor of all `values`, 0 if there are none.
+pub fn gcd_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// Least common multiple of all `values`, 1 if there are none, or `None` if
+/// it doesn't fit.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| lcm(acc, value))
+}
+
+/// Extended Euclidean algorithm: `(g, x, y)` with `g = gcd(a, b)` and
+/// `a * x + b * y = g`.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::ZERO {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// `(a + b) % m` for `a` and `b` in `0..m`, without overflowing.
+fn add_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `(a * b) % m` for `a` and `b` in `0..m`, without overflowing.
+fn mul_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    // Double and add, so that nothing gets larger than `2 * m`.
+    let (mut a, mut b, mut product) = (a, b, T::ZERO);
+    while b > T::ZERO {
+        if b % two() == T::ONE {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / two();
+    }
+    product
+}
+
+fn check_modulus<T: Integer>(m: T) {
+    assert!(m > T::ZERO, "modulus {} isn't positive", m);
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+///
+/// Panics if `m` isn't positive.
+pub fn mod_inverse<T: Integer>(a: T, m: T) -> Option<T> {
+    check_modulus(m);
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// `base` to the power of `exp`, modulo `m`, in `0..m`.
+///
+/// Panics if `m` isn't positive or `exp` is negative.
+pub fn mod_pow<T: Integer>(base: T, exp: T, m: T) -> T {
+    check_modulus(m);
+    assert!(exp >= T::ZERO, "negative exponent {}", exp);
+    let (mut base, mut exp) = (base.rem_euclid(m), exp);
+    let mut result = T::ONE % m;
+    while exp > T::ZERO {
+        if exp % two() == T::ONE {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / two();
+    }
+    result
+}
+
+/// Chinese remainder theorem: combines the congruences `x ≡ r (mod m)`,
+/// given as `(r, m)`, into a single `(r, m)` with `r` in `0..m`, where `m` is
+/// the least common multiple of the moduli. The moduli don't need to be
+/// coprime. No congruences give `(0, 1)`.
+///
+/// Fails if the congruences contradict each other or the combined modulus
+/// doesn't fit. Panics if a modulus isn't positive.
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Result<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), (r2, m2)| {
+            check_modulus(m2);
+            let r2 = r2.rem_euclid(m2);
+            let (g, inverse, _) = extended_gcd(m1, m2);
+            let diff = r2 - r1;
+            if diff % g != T::ZERO {
+                return Err(Error::solve(format!(
+                    "x ≡ {} (mod {}) contradicts x ≡ {} (mod {})",
+                    r2, m2, r1, m1
+                )));
+            }
+            let m2_g = m2 / g;
+            let m = m1
+                .checked_mul(m2_g)
+                .ok_or_else(|| Error::solve(format!("lcm({}, {}) overflows", m1, m2)))?;
+            // x = r1 + m1 * t, where m1 * t ≡ diff (mod m2) fixes t in 0..m2/g.
+            let t = mul_mod((diff / g).rem_euclid(m2_g), inverse.rem_euclid(m2_g), m2_g);
+            Ok((r1 + m1 * t, m))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12i64, -18), 6);
+        assert_eq!(gcd(0i64, 0), 0);
+        assert_eq!(lcm(4i64, -6), Some(12));
+        assert_eq!(lcm(0i64, 5), Some(0));
+        assert_eq!(gcd_all([12i64, 18, 27]), 3);
+        assert_eq!(lcm_all([2i64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all::<i64>([]), Some(1));
+        assert_eq!(lcm_all([i64::MAX, 2]), None);
+        assert_eq!(lcm_all([i64::MAX as i128, 2]), Some(i64::MAX as i128 * 2));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240i64, 46), (-7, 3), (0, 5), (5, 0), (17, -17)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(6i64, 9), None);
+        assert_eq!(mod_pow(2i64, 10, 1000), 24);
+        assert_eq!(mod_pow(-2i64, 3, 5), 2);
+        assert_eq!(mod_pow(7i64, 0, 1), 0);
+        // Squares of residues this large overflow even i128.
+        let m = (1i128 << 100) + 277;
+        assert_eq!(mod_pow(m - 1, 3, m), m - 1);
+        assert_eq!(mod_pow(3i128, m - 1, m), mod_pow(9i128, (m - 1) / 2, m));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        // Not coprime, but consistent.
+        assert_eq!(crt([(3i64, 4), (5, 6)]), Ok((11, 12)));
+        assert_eq!(crt([(-1i64, 4)]), Ok((3, 4)));
+        assert_eq!(crt::<i64>([]), Ok((0, 1)));
+        assert_eq!(
+            crt([(1i64, 4), (2, 6)]).unwrap_err().to_string(),
+            "x ≡ 2 (mod 6) contradicts x ≡ 1 (mod 4)"
+        );
+        assert!(crt([(1i64, i64::MAX), (0, 2)]).is_err());
+        assert_eq!(
+            crt([(1i128, i64::MAX as i128), (0, 2)]),
+            Ok((i64::MAX as i128 + 1, i64::MAX as i128 * 2))
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! A cache for recursive functions that reach the same arguments over and
+//! over, which makes them take time linear in the number of distinct
+//! arguments.
+//!
+//! The function takes the cache as its first argument and wraps its body in
+//! `Memo::call`, which hands the cache back for the recursive calls:
+//!
+//! ```
+//! use aoc_core::utils::memo::Memo;
+//!
+//! fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+//!     memo.call(n, |memo| match n {
+//!         0 | 1 => n,
+//!         _ => fib(memo, n - 1) + fib(memo, n - 2),
+//!     })
+//! }
+//!
+//! assert_eq!(fib(&mut Memo::new(), 90), 2_880_067_194_370_816_120);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// The results of a function by its arguments, with statistics on how often
+/// they were looked up.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    stats: Stats,
+}
+
+/// How many lookups found a result, and how many had to compute it.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} hits, {} misses", self.hits, self.misses)
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            limit: None,
+            stats: Stats::default(),
+        }
+    }
+
+    /// A cache that keeps at most `limit` results. Once it is full, new
+    /// results are still returned but no longer kept.
+    pub fn bounded(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// The result for `key`, computed by `f` unless it is cached. `f` gets
+    /// the cache for its recursive calls.
+    pub fn call(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = f(self);
+        if self.limit.is_none_or(|limit| self.cache.len() < limit) {
+            self.cache.insert(key, value.clone());
+        }
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Forgets all results, but not the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number of ways to climb `n` stairs taking 1 or 2 at a time.
+    fn stairs(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.call(n, |memo| match n {
+            0 | 1 => 1,
+            _ => stairs(memo, n - 1) + stairs(memo, n - 2),
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(stairs(&mut memo, 80), 37_889_062_373_143_906);
+        // Every n is computed once, and n up to 78 is looked up again by n + 2.
+        assert_eq!(memo.len(), 81);
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 78,
+                misses: 81
+            }
+        );
+        assert_eq!(memo.get(&10), Some(&89));
+
+        assert_eq!(stairs(&mut memo, 80), 37_889_062_373_143_906);
+        assert_eq!(memo.stats().to_string(), "79 hits, 81 misses");
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+
+    #[test]
+    fn test_bounded() {
+        let mut memo = Memo::bounded(5);
+        assert_eq!(stairs(&mut memo, 30), 1_346_269);
+        assert_eq!(memo.len(), 5);
+        // The first results to finish are the smallest n, which are kept.
+        assert_eq!(memo.get(&4), Some(&5));
+        assert_eq!(memo.get(&5), None);
+
+        let mut memo = Memo::bounded(0);
+        assert_eq!(stairs(&mut memo, 10), 89);
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats().misses, 177);
+    }
+}
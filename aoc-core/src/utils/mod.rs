@@ -0,0 +1,17 @@
+mod coord;
+pub mod cycle;
+pub mod dsu;
+mod grid;
+pub mod interval;
+pub mod linalg;
+pub mod math;
+pub mod memo;
+mod parse;
+pub mod search;
+
+pub use coord::{Coord, Direction, Direction8};
+pub use grid::{Grid, Regions};
+pub use parse::{
+    blocks, header, integer_tuple, integers, key_value, offset_of, parse_blocks, parse_lines,
+    parse_numbers_from_string, parse_numbers_from_string_comma, parse_token, FromIntegers,
+};
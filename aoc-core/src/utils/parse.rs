@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use crate::{Error, Result};
+
+/// Byte offset of `inner` inside `outer`, if `inner` is a slice of it.
+pub fn offset_of(outer: &str, inner: &str) -> Option<usize> {
+    let offset = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+    (offset + inner.len() <= outer.len()).then_some(offset)
+}
+
+/// Parses `token`, a slice of `context`, reporting its position on failure.
+pub fn parse_token<T: FromStr>(context: &str, token: &str, expected: &str) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| Error::parse_at(context, token, expected))
+}
+
+/// Parses every line of `input`, giving errors their line number.
+pub fn parse_lines<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .map(|line| f(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+pub fn parse_numbers_from_string(input: &str) -> Result<Vec<i32>> {
+    input
+        .split_whitespace()
+        .map(|s| parse_token(input, s, "integer"))
+        .collect()
+}
+
+pub fn parse_numbers_from_string_comma(input: &str) -> Result<Vec<i32>> {
+    input
+        .split(",")
+        .map(|s| parse_token(input, s, "integer"))
+        .collect()
+}
+
+/// The integers in `line`, skipping anything else. A `-` right before one is
+/// its sign, unless it follows a digit as in the range `1-5`.
+fn integer_tokens(line: &str) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut idx = 0;
+    std::iter::from_fn(move || {
+        while idx < bytes.len() && !bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        if idx == bytes.len() {
+            return None;
+        }
+        let mut start = idx;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        Some(&line[start..idx])
+    })
+}
+
+/// Parses all integers in `line`, whatever is around them:
+/// `Button A: X+94, Y=-34` gives `[94, -34]`.
+pub fn integers<T: FromStr>(line: &str) -> Result<Vec<T>> {
+    integer_tokens(line)
+        .map(|token| parse_token(line, token, "integer"))
+        .collect()
+}
+
+/// A tuple parsed by `integer_tuple`, with an element per integer.
+pub trait FromIntegers: Sized {
+    const COUNT: usize;
+
+    /// Parses exactly `COUNT` tokens, which are slices of `line`.
+    fn from_tokens(line: &str, tokens: &[&str]) -> Result<Self>;
+}
+
+macro_rules! from_integers {
+    ($count:literal: $($elem:ident $idx:tt),+) => {
+        impl<$($elem: FromStr),+> FromIntegers for ($($elem,)+) {
+            const COUNT: usize = $count;
+
+            fn from_tokens(line: &str, tokens: &[&str]) -> Result<Self> {
+                Ok(($(parse_token::<$elem>(line, tokens[$idx], "integer")?,)+))
+            }
+        }
+    };
+}
+
+from_integers!(2: A 0, B 1);
+from_integers!(3: A 0, B 1, C 2);
+from_integers!(4: A 0, B 1, C 2, D 3);
+from_integers!(5: A 0, B 1, C 2, D 3, E 4);
+from_integers!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Parses the integers in `line` into a tuple, which has to have exactly as
+/// many elements: `p=0,4 v=3,-3` gives `(0, 4, 3, -3)`.
+pub fn integer_tuple<T: FromIntegers>(line: &str) -> Result<T> {
+    let tokens: Vec<&str> = integer_tokens(line).collect();
+    if tokens.len() != T::COUNT {
+        let expected = format!("{} integers, not {}", T::COUNT, tokens.len());
+        return Err(Error::parse_at(line, line, expected));
+    }
+    T::from_tokens(line, &tokens)
+}
+
+/// Splits `input` at blank lines into blocks of lines, without the line
+/// breaks around them. The blocks are slices of `input`.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let is_blank = |line: &&str| line.trim().is_empty();
+    let mut lines = input.lines().peekable();
+    std::iter::from_fn(move || {
+        let first = lines.find(|line| !is_blank(line))?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !is_blank(line)) {
+            last = line;
+        }
+        let start = offset_of(input, first)?;
+        let end = offset_of(input, last)? + last.len();
+        Some(&input[start..end])
+    })
+}
+
+/// Parses every block of `input`, see `blocks`, giving errors their line
+/// number.
+pub fn parse_blocks<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    blocks(input)
+        .map(|block| f(block).map_err(|err| err.within(input, block)))
+        .collect()
+}
+
+/// Splits a `key: value` line at its first colon, trimming both sides.
+pub fn key_value(line: &str) -> Result<(&str, &str)> {
+    line.split_once(':')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| Error::parse_at(line, line, "`key: value`"))
+}
+
+/// The value of a `key: value` line, which has to have the given key.
+pub fn header<'a>(line: &'a str, key: &str) -> Result<&'a str> {
+    let (found, value) = key_value(line)?;
+    if found != key {
+        return Err(Error::parse_at(line, found, format!("`{}: ...`", key)));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(integers("Button A: X+94, Y=-34"), Ok(vec![94, -34]));
+        assert_eq!(integers("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(integers("-1 --2 x-3"), Ok(vec![-1, -2, -3]));
+        assert_eq!(integers::<i32>("no numbers"), Ok(vec![]));
+        let err = integers::<u8>("1, -2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 4: expected integer, found \"-2\""
+        );
+    }
+
+    #[test]
+    fn test_integer_tuple() {
+        assert_eq!(integer_tuple("p=0,4 v=3,-3"), Ok((0, 4, 3, -3)));
+        let (seed, count): (u64, usize) = integer_tuple("79 14").unwrap();
+        assert_eq!((seed, count), (79, 14));
+        let err = integer_tuple::<(i32, i32)>("1 2 3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected 2 integers, not 3, found \"1 2 3\""
+        );
+        let err = integer_tuple::<(i32, u8)>("1 300").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected integer, found \"300\""
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\na\nb\n\n\nc\n  \nd\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["a\nb", "c", "d"]);
+        assert_eq!(blocks("").count(), 0);
+
+        let err = parse_blocks(input, |block| {
+            parse_lines(block, |line| parse_token::<i32>(line, line, "integer"))
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected integer, found \"a\""
+        );
+    }
+
+    #[test]
+    fn test_headers() {
+        assert_eq!(key_value("Time:  7 15"), Ok(("Time", "7 15")));
+        assert_eq!(key_value("Program: 0,1:2"), Ok(("Program", "0,1:2")));
+        assert!(key_value("no colon").is_err());
+        assert_eq!(header("Register A: 729", "Register A"), Ok("729"));
+        let err = header("Register B: 0", "Register A").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `Register A: ...`, found \"Register B\""
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! Shortest paths over graphs given by a neighbour function.
+//!
+//! The searches start from any number of nodes and stop once every node as
+//! close as the nearest goal has been settled, so that all optimal paths to
+//! the goals are known. Use `|_| false` as the goal to explore everything
+//! reachable. Edge costs are expected to be positive.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use super::memo::Memo;
+
+/// What a search found: the distance to every reached node and, for each,
+/// its predecessors on the shortest paths to it, which form a DAG.
+#[derive(Clone, Debug)]
+pub struct Paths<N, C> {
+    pub distances: HashMap<N, C>,
+    /// Start nodes have none.
+    pub predecessors: HashMap<N, Vec<N>>,
+    /// The goals reached, all at the same, smallest distance.
+    pub goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distance to the nearest goal, if any was reached.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// One of the shortest paths from a start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut preds = self.predecessors.get(node)?;
+        while let Some(pred) = preds.first() {
+            path.push(pred.clone());
+            preds = &self.predecessors[pred];
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on any shortest path to one of `targets`.
+    pub fn nodes_on_paths<'a>(&self, targets: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&N> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(&self.predecessors[node]);
+            }
+        }
+        seen
+    }
+
+    /// Number of distinct shortest paths from the starts to `node`.
+    pub fn path_count(&self, node: &N) -> u64 {
+        fn count<N: Clone + Eq + Hash>(
+            preds: &HashMap<N, Vec<N>>,
+            node: &N,
+            memo: &mut Memo<N, u64>,
+        ) -> u64 {
+            memo.call(node.clone(), |memo| match preds.get(node) {
+                None => 0,
+                Some(node_preds) if node_preds.is_empty() => 1,
+                Some(node_preds) => node_preds.iter().map(|pred| count(preds, pred, memo)).sum(),
+            })
+        }
+        count(&self.predecessors, node, &mut Memo::new())
+    }
+
+    fn add_start(&mut self, start: N, zero: C) -> bool {
+        if self.distances.contains_key(&start) {
+            return false;
+        }
+        self.distances.insert(start.clone(), zero);
+        self.predecessors.insert(start, Vec::new());
+        true
+    }
+}
+
+/// Breadth-first search, for graphs whose edges all cost 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.add_start(start.clone(), 0) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let dist = paths.distances[&node];
+        if paths.goal_distance().is_some_and(|best| dist > best) {
+            break;
+        }
+        if is_goal(&node) {
+            paths.goals.push(node);
+            continue;
+        }
+        for next in neighbors(&node) {
+            match paths.distances.get(&next) {
+                Some(&d) if d == dist + 1 => {
+                    paths
+                        .predecessors
+                        .get_mut(&next)
+                        .unwrap()
+                        .push(node.clone());
+                }
+                Some(_) => {}
+                None => {
+                    paths.distances.insert(next.clone(), dist + 1);
+                    paths.predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm, for graphs with edges of different costs.
+/// `neighbors` gives each neighbour together with the cost to reach it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search, Dijkstra's algorithm guided by `heuristic`, an estimate of the
+/// cost from a node to the nearest goal. The estimate must never exceed the
+/// real cost, nor drop by more than the cost of an edge along it.
+///
+/// Only nodes that might be on a shortest path to a goal are settled, so the
+/// distances of the others are upper bounds at best.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    let mut paths = Paths::new();
+    // Nodes don't need to be ordered, the heap refers to them by index.
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if paths.add_start(start.clone(), zero) {
+            heap.push(Reverse((heuristic(&start), zero, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    let mut best = None;
+    while let Some(Reverse((estimate, dist, idx))) = heap.pop() {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        let node = nodes[idx].clone();
+        if paths.distances[&node] < dist {
+            continue;
+        }
+        if is_goal(&node) {
+            best.get_or_insert(estimate);
+            paths.goals.push(node);
+            continue;
+        }
+        for (next, cost) in neighbors(&node) {
+            let next_dist = dist + cost;
+            match paths.distances.get(&next) {
+                Some(&d) if d == next_dist => {
+                    paths
+                        .predecessors
+                        .get_mut(&next)
+                        .unwrap()
+                        .push(node.clone());
+                }
+                Some(&d) if d < next_dist => {}
+                _ => {
+                    paths.distances.insert(next.clone(), next_dist);
+                    paths.predecessors.insert(next.clone(), vec![node.clone()]);
+                    heap.push(Reverse((
+                        next_dist + heuristic(&next),
+                        next_dist,
+                        nodes.len(),
+                    )));
+                    nodes.push(next);
+                }
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Coord, Grid};
+
+    const MAZE: &str = "\
+S...#
+.##.#
+....E
+.#...";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Ok).unwrap()
+    }
+
+    fn open_neighbors(maze: &Grid<char>, pos: Coord) -> impl Iterator<Item = Coord> + '_ {
+        pos.neighbors4()
+            .filter(|&next| maze.get(next).is_some_and(|&c| c != '#'))
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let start = maze.find(&'S').unwrap();
+        let end = maze.find(&'E').unwrap();
+        let paths = bfs(
+            [start],
+            |&pos| open_neighbors(&maze, pos),
+            |&pos| pos == end,
+        );
+        assert_eq!(paths.goal_distance(), Some(6));
+        assert_eq!(paths.goals, vec![end]);
+        // Along the top or down first and along the middle row.
+        assert_eq!(paths.path_count(&end), 2);
+        let path = paths.path(&end).unwrap();
+        assert_eq!((path[0], path[6]), (start, end));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+        let on_paths = paths.nodes_on_paths([&end]);
+        assert_eq!(on_paths.len(), 11);
+        assert!(!on_paths.contains(&Coord::new(0, 3)));
+
+        let everything = bfs([start], |&pos| open_neighbors(&maze, pos), |_| false);
+        assert_eq!(everything.goals, vec![]);
+        assert_eq!(everything.distance(&Coord::new(4, 3)), Some(7));
+        assert_eq!(everything.distance(&Coord::new(4, 0)), None);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let maze = maze();
+        let starts = [Coord::new(0, 3), Coord::new(4, 3)];
+        let paths = bfs(starts, |&pos| open_neighbors(&maze, pos), |_| false);
+        assert_eq!(paths.distance(&Coord::new(2, 3)), Some(2));
+        assert_eq!(paths.distance(&Coord::new(0, 0)), Some(3));
+        assert_eq!(paths.path_count(&Coord::new(2, 2)), 4);
+        assert_eq!(paths.predecessors[&starts[0]], vec![]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going down costs 10, everything else 1.
+        let maze = maze();
+        let cost = |from: Coord, to: Coord| if to.y > from.y { 10 } else { 1 };
+        let end = maze.find(&'E').unwrap();
+        let neighbors =
+            |&pos: &Coord| open_neighbors(&maze, pos).map(move |next| (next, cost(pos, next)));
+        let paths = dijkstra([Coord::ORIGIN], neighbors, |&pos| pos == end);
+        assert_eq!(paths.goal_distance(), Some(24));
+        assert_eq!(paths.path_count(&end), 2);
+
+        let guided = astar(
+            [Coord::ORIGIN],
+            neighbors,
+            |pos| pos.manhattan(end),
+            |&pos| pos == end,
+        );
+        assert_eq!(guided.goal_distance(), Some(24));
+        assert_eq!(
+            guided.nodes_on_paths(&guided.goals),
+            paths.nodes_on_paths(&paths.goals)
+        );
+    }
+}
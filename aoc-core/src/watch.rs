@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::input::{workspace_dir, InputConfig, InputSource};
+use crate::runner::PartResult;
+use crate::Answer;
+
+/// Source file of a day's solver in the workspace.
+pub fn source_path(year: u16, day: u8) -> PathBuf {
+    workspace_dir().join(format!("aoc-{}/src/day{:02}.rs", year, day))
+}
+
+/// What a file looked like when last polled, `None` if it didn't exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &PathBuf) -> Stamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// Notices changes to the files a day depends on by polling their
+/// modification times and sizes, which works the same on every platform.
+///
+/// Watched are the day's puzzle input, all its examples, including ones
+/// added later, an input file given instead and the solver's source. The
+/// source can't be reloaded into a running binary, it's up to the caller to
+/// have it rebuilt.
+pub struct Watcher {
+    year: u16,
+    day: u8,
+    input: InputConfig,
+    stamps: BTreeMap<PathBuf, Stamp>,
+}
+
+impl Watcher {
+    pub fn new(year: u16, day: u8, input: &InputConfig) -> Self {
+        let mut watcher = Self {
+            year,
+            day,
+            input: input.clone(),
+            stamps: BTreeMap::new(),
+        };
+        watcher.stamps = watcher.take_stamps();
+        watcher
+    }
+
+    pub fn paths(&self) -> Vec<PathBuf> {
+        let data_dir = &self.input.data_dir;
+        let mut paths = vec![
+            source_path(self.year, self.day),
+            data_dir.input_path(self.year, self.day),
+        ];
+        let prefix = format!("day{:02}_example", self.day);
+        if let Ok(entries) = fs::read_dir(data_dir.year_dir(self.year)) {
+            let mut examples: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| {
+                    let name = entry.file_name();
+                    let name = name.to_string_lossy();
+                    name.starts_with(&prefix) && name.ends_with(".txt")
+                })
+                .map(|entry| entry.path())
+                .collect();
+            examples.sort();
+            paths.extend(examples);
+        }
+        if let InputSource::File(path) = &self.input.source {
+            paths.push(path.clone());
+        }
+        paths
+    }
+
+    fn take_stamps(&self) -> BTreeMap<PathBuf, Stamp> {
+        self.paths()
+            .into_iter()
+            .map(|path| {
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect()
+    }
+
+    /// The files that were created, changed or removed since the last poll.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let stamps = self.take_stamps();
+        let mut changed: Vec<PathBuf> = stamps
+            .iter()
+            .filter(|(path, stamp)| self.stamps.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.stamps
+                .keys()
+                .filter(|path| !stamps.contains_key(*path))
+                .cloned(),
+        );
+        self.stamps = stamps;
+        changed
+    }
+}
+
+/// How the answer of a part compares to the previous run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    /// There was no previous run.
+    First,
+    Unchanged,
+    /// The previous answer, if the part had one.
+    Changed(Option<Answer>),
+}
+
+impl Change {
+    pub fn between(previous: Option<&PartResult>, current: &PartResult) -> Change {
+        match previous {
+            None => Change::First,
+            Some(prev) if prev.answer == current.answer && prev.status == current.status => {
+                Change::Unchanged
+            }
+            Some(prev) => Change::Changed(prev.answer.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::First => write!(f, "first run"),
+            Change::Unchanged => write!(f, "unchanged"),
+            Change::Changed(Some(answer)) => write!(f, "changed from {}", answer.summary()),
+            Change::Changed(None) => write!(f, "changed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::DataDir;
+    use crate::runner::{Part, Status};
+    use std::env;
+    use std::fs::File;
+    use std::time::Duration;
+
+    #[test]
+    fn test_poll() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2024")).unwrap();
+        let input = dir.join("2024/day05.txt");
+        fs::write(&input, "1\n").unwrap();
+        fs::write(dir.join("2024/day06_example1.txt"), "6\n").unwrap();
+
+        let config = InputConfig {
+            data_dir: DataDir::new(dir.clone()),
+            ..InputConfig::default()
+        };
+        let mut watcher = Watcher::new(2024, 5, &config);
+        assert_eq!(watcher.poll(), Vec::<PathBuf>::new());
+
+        // Same size, so only the modification time tells.
+        fs::write(&input, "2\n").unwrap();
+        let later = SystemTime::now() + Duration::from_secs(10);
+        File::options()
+            .write(true)
+            .open(&input)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(watcher.poll(), vec![input.clone()]);
+        assert_eq!(watcher.poll(), Vec::<PathBuf>::new());
+
+        let example = dir.join("2024/day05_example1.txt");
+        fs::write(&example, "part1: 3\n---\n3\n").unwrap();
+        assert_eq!(watcher.poll(), vec![example.clone()]);
+        fs::write(dir.join("2024/day06_example1.txt"), "66\n").unwrap();
+        assert_eq!(watcher.poll(), Vec::<PathBuf>::new());
+        fs::remove_file(&example).unwrap();
+        assert_eq!(watcher.poll(), vec![example]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_change() {
+        let result = |answer: Option<Answer>| PartResult {
+            year: 2024,
+            day: 5,
+            part: Part::One,
+            status: if answer.is_some() {
+                Status::Ok
+            } else {
+                Status::Timeout
+            },
+            answer,
+            time: None,
+            parse_time: None,
+        };
+        let first = result(Some(143.into()));
+        assert_eq!(Change::between(None, &first), Change::First);
+        assert_eq!(
+            Change::between(Some(&first), &result(Some(143.into()))),
+            Change::Unchanged
+        );
+        let change = Change::between(Some(&first), &result(Some(123.into())));
+        assert_eq!(change.to_string(), "changed from 143");
+        assert_eq!(
+            Change::between(Some(&result(None)), &first),
+            Change::Changed(None)
+        );
+    }
+}